@@ -75,6 +75,8 @@ pub struct Call {
     pub caller_address: Address,
     /// Address where this call is being executed
     pub address: Address,
+    /// The account at `address` was created in the current transaction
+    pub is_created_in_tx: bool,
     /// Code Source
     pub code_source: CodeSource,
     /// Code Hash
@@ -287,9 +287,23 @@ pub struct CopyEvent {
     pub rw_counter_start: RWCounter,
     /// Represents the list of (bytes, is_code) copied during this copy event
     pub bytes: Vec<(u8, bool)>,
+    /// Whether the bytes are copied from the highest address down to the
+    /// lowest one. In that case `bytes` is kept in copy order, i.e. its first
+    /// element is the last byte of the source range.
+    pub is_reverse: bool,
 }
 
 impl CopyEvent {
+    /// Offset from `src_addr`/`dst_addr` of the byte copied at step index
+    pub fn addr_offset(&self, step_index: usize) -> u64 {
+        let byte_index = u64::try_from(step_index).unwrap() / 2;
+        if self.is_reverse {
+            u64::try_from(self.bytes.len()).unwrap() - 1 - byte_index
+        } else {
+            byte_index
+        }
+    }
+
     /// rw counter at step index
     pub fn rw_counter(&self, step_index: usize) -> u64 {
         u64::try_from(self.rw_counter_start.0).unwrap() + self.rw_counter_increase(step_index)
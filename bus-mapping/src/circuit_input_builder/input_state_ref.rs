@@ -10,8 +10,8 @@ use crate::{
     exec_trace::OperationRef,
     operation::{
         AccountField, AccountOp, CallContextField, CallContextOp, MemoryOp, Op, OpEnum, Operation,
-        StackOp, Target, TxAccessListAccountOp, TxLogField, TxLogOp, TxReceiptField, TxReceiptOp,
-        RW,
+        StackOp, Target, TxAccessListAccountOp, TxCreatedAccountOp, TxLogField, TxLogOp,
+        TxReceiptField, TxReceiptOp, RW,
     },
     precompile::{is_precompiled, PrecompileCalls},
     state_db::{CodeDB, StateDB},
@@ -567,6 +567,24 @@ impl<'a> CircuitInputStateRef<'a> {
         )
     }
 
+    /// Mark `address` as created in the current transaction.
+    pub fn tx_created_account_write(
+        &mut self,
+        step: &mut ExecStep,
+        address: Address,
+    ) -> Result<(), Error> {
+        let is_created = self.sdb.is_created_account(&address);
+        self.push_op_reversible(
+            step,
+            TxCreatedAccountOp {
+                tx_id: self.tx_ctx.id(),
+                address,
+                is_created: true,
+                is_created_prev: is_created,
+            },
+        )
+    }
+
     /// Push 2 reversible [`AccountOp`] to update `sender` and `receiver`'s
     /// balance by `value`. If `fee` is existing (not None), also need to push 1
     /// non-reversible [`AccountOp`] to update `sender` balance by `fee`.
@@ -859,6 +877,11 @@ impl<'a> CircuitInputStateRef<'a> {
             ),
         };
 
+        let is_created_in_tx = match kind {
+            CallKind::Create | CallKind::Create2 => true,
+            _ => self.sdb.is_created_account(&address),
+        };
+
         let (code_source, code_hash) = match kind {
             CallKind::Create | CallKind::Create2 => {
                 let init_code = get_create_init_code(caller_ctx, step)?.to_vec();
@@ -908,6 +931,7 @@ impl<'a> CircuitInputStateRef<'a> {
             rw_counter_end_of_reversion: 0,
             caller_address,
             address,
+            is_created_in_tx,
             code_source,
             code_hash,
             depth: caller.depth + 1,
@@ -959,6 +983,14 @@ impl<'a> CircuitInputStateRef<'a> {
                     None
                 }
             }
+            OperationRef(Target::TxCreatedAccount, idx) => {
+                let operation = &self.block.container.tx_created_account[*idx];
+                if operation.rw().is_write() && operation.reversible() {
+                    Some(OpEnum::TxCreatedAccount(operation.op().reverse()))
+                } else {
+                    None
+                }
+            }
             OperationRef(Target::TxRefund, idx) => {
                 let operation = &self.block.container.tx_refund[*idx];
                 if operation.rw().is_write() && operation.reversible() {
@@ -1007,6 +1039,14 @@ impl<'a> CircuitInputStateRef<'a> {
                         .remove_account_storage_from_access_list(&(op.address, op.key));
                 }
             }
+            OpEnum::TxCreatedAccount(op) => {
+                if !op.is_created_prev && op.is_created {
+                    self.sdb.add_created_account(op.address);
+                }
+                if op.is_created_prev && !op.is_created {
+                    self.sdb.remove_created_account(&op.address);
+                }
+            }
             OpEnum::Account(op) => self.check_update_sdb_account(RW::WRITE, op),
             OpEnum::TxRefund(op) => {
                 self.sdb.set_refund(op.value);
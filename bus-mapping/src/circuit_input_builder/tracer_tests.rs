@@ -85,6 +85,7 @@ fn mock_internal_create() -> Call {
         rw_counter_end_of_reversion: 0,
         caller_address: *ADDR_A,
         address: *ADDR_B,
+        is_created_in_tx: true,
         code_source: CodeSource::Memory,
         code_hash: Hash::zero(),
         depth: 2,
@@ -111,6 +112,7 @@ fn mock_root_create() -> Call {
         rw_counter_end_of_reversion: 0,
         caller_address: *ADDR_A,
         address: *ADDR_B,
+        is_created_in_tx: true,
         code_source: CodeSource::Memory,
         code_hash: Hash::zero(),
         depth: 1,
@@ -1654,6 +1656,7 @@ fn tracer_err_write_protection(is_call: bool) {
         rw_counter_end_of_reversion: 0,
         caller_address: *ADDR_A,
         address: *ADDR_B,
+        is_created_in_tx: false,
         code_source: CodeSource::Address(*ADDR_B),
         code_hash: Hash::zero(),
         depth: 2,
@@ -247,6 +247,7 @@ impl Transaction {
                 is_success,
                 caller_address: eth_tx.from,
                 address: get_contract_address(eth_tx.from, eth_tx.nonce),
+                is_created_in_tx: true,
                 code_source: CodeSource::Tx,
                 code_hash,
                 depth: 1,
@@ -169,6 +169,7 @@ fn gen_begin_tx_steps(state: &mut CircuitInputStateRef) -> Result<ExecStep, Erro
                     log_id: None,
                     rw_counter_start: state.block_ctx.rwc,
                     bytes: state.tx.call_data.iter().map(|b| (*b, false)).collect(),
+                    is_reverse: false,
                 },
             );
         }
@@ -191,6 +192,7 @@ fn gen_begin_tx_steps(state: &mut CircuitInputStateRef) -> Result<ExecStep, Erro
                     value_prev: 0.into(),
                 },
             )?;
+            state.tx_created_account_write(&mut exec_step, call.address)?;
             for (field, value) in [
                 (CallContextField::Depth, call.depth.into()),
                 (
@@ -295,8 +297,8 @@ fn gen_end_tx_steps(state: &mut CircuitInputStateRef) -> Result<ExecStep, Error>
         },
     )?;
 
-    let effective_refund =
-        refund.min((state.tx.gas() - exec_step.gas_left) / MAX_REFUND_QUOTIENT_OF_GAS_USED as u64);
+    let gas_used = state.tx.gas() - exec_step.gas_left;
+    let effective_refund = refund.min(gas_used / MAX_REFUND_QUOTIENT_OF_GAS_USED as u64);
     let (found, caller_account) = state.sdb.get_account(&call.caller_address);
     if !found {
         return Err(Error::AccountNotFound(call.caller_address));
@@ -319,7 +321,9 @@ fn gen_end_tx_steps(state: &mut CircuitInputStateRef) -> Result<ExecStep, Error>
         return Err(Error::AccountNotFound(state.block.coinbase));
     }
     let coinbase_exist = !coinbase_account.is_empty();
-    let coinbase_transfer_value = effective_tip * (state.tx.gas() - exec_step.gas_left);
+    // The refunded gas is neither paid to the coinbase nor counted as used.
+    let gas_used_after_refund = gas_used - effective_refund;
+    let coinbase_transfer_value = effective_tip * gas_used_after_refund;
     state.account_read(
         &mut exec_step,
         state.block.coinbase,
@@ -339,7 +343,7 @@ fn gen_end_tx_steps(state: &mut CircuitInputStateRef) -> Result<ExecStep, Error>
         false,
     )?;
 
-    end_tx(state, &mut exec_step, &call)?;
+    end_tx(state, &mut exec_step, &call, gas_used_after_refund)?;
 
     Ok(exec_step)
 }
@@ -363,9 +367,10 @@ pub(crate) fn end_tx(
     state: &mut CircuitInputStateRef,
     exec_step: &mut ExecStep,
     call: &Call,
+    gas_used: u64,
 ) -> Result<(), Error> {
     // Write the tx receipt
-    write_tx_receipt(state, exec_step, call.is_persistent)?;
+    write_tx_receipt(state, exec_step, call.is_persistent, gas_used)?;
 
     Ok(())
 }
@@ -374,6 +379,7 @@ fn write_tx_receipt(
     state: &mut CircuitInputStateRef,
     exec_step: &mut ExecStep,
     is_persistent: bool,
+    gas_used: u64,
 ) -> Result<(), Error> {
     // handle tx receipt tag
     state.tx_receipt_write(
@@ -401,7 +407,7 @@ fn write_tx_receipt(
         )?;
     }
 
-    state.block_ctx.cumulative_gas_used += state.tx.gas() - exec_step.gas_left;
+    state.block_ctx.cumulative_gas_used += gas_used;
     state.tx_receipt_write(
         exec_step,
         state.tx_ctx.id(),
@@ -411,3 +417,98 @@ fn write_tx_receipt(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod end_tx_tests {
+    use crate::{
+        circuit_input_builder::ExecState,
+        mock::BlockData,
+        operation::{AccountField, Target, TxReceiptField, RW},
+    };
+    use eth_types::{bytecode, geth_types::GethData, Word};
+    use mock::{test_ctx::helpers::tx_from_1_to_0, TestContext, MOCK_ACCOUNTS};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn end_tx_excludes_refund_from_gas_used() {
+        // Clearing a non-zero storage slot earns a refund.
+        let code = bytecode! {
+            PUSH1(0x00u64)
+            PUSH1(0x00u64)
+            SSTORE
+            STOP
+        };
+
+        // Get the execution steps from the external tracer
+        let block: GethData = TestContext::<2, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(MOCK_ACCOUNTS[0])
+                    .balance(Word::from(10u64.pow(19)))
+                    .code(code)
+                    .storage(vec![(0x00u64.into(), 0x6fu64.into())].into_iter());
+                accs[1]
+                    .address(MOCK_ACCOUNTS[1])
+                    .balance(Word::from(10u64.pow(19)));
+            },
+            tx_from_1_to_0,
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap()
+        .into();
+
+        let builder = BlockData::new_from_geth_data(block.clone()).new_circuit_input_builder();
+        let builder = builder
+            .handle_block(&block.eth_block, &block.geth_traces)
+            .unwrap();
+
+        let tx = &builder.block.txs()[0];
+        let step = tx
+            .steps()
+            .iter()
+            .find(|step| step.exec_state == ExecState::EndTx)
+            .unwrap();
+        let container = &builder.block.container;
+
+        let refund = step
+            .bus_mapping_instance
+            .iter()
+            .find(|op_ref| op_ref.target() == Target::TxRefund)
+            .map(|op_ref| container.tx_refund[op_ref.as_usize()].op().value)
+            .unwrap();
+        assert!(refund > 0);
+
+        // geth reports the gas used after the refund.
+        let gas_used_after_refund = block.geth_traces[0].gas;
+        assert!(gas_used_after_refund < tx.gas() - step.gas_left);
+
+        // The coinbase is only paid for the gas left after the refund.
+        let coinbase_balance = step
+            .bus_mapping_instance
+            .iter()
+            .filter(|op_ref| op_ref.target() == Target::Account)
+            .map(|op_ref| &container.account[op_ref.as_usize()])
+            .find(|operation| {
+                operation.rw() == RW::WRITE
+                    && operation.op().address == builder.block.coinbase
+                    && operation.op().field == AccountField::Balance
+            })
+            .unwrap();
+        let effective_tip = tx.gas_price - builder.block.base_fee;
+        assert_eq!(
+            coinbase_balance.op().value - coinbase_balance.op().value_prev,
+            effective_tip * gas_used_after_refund
+        );
+
+        // The receipt doesn't count the refunded gas as used either.
+        let cumulative_gas_used = step
+            .bus_mapping_instance
+            .iter()
+            .filter(|op_ref| op_ref.target() == Target::TxReceipt)
+            .map(|op_ref| container.tx_receipt[op_ref.as_usize()].op())
+            .find(|op| op.field == TxReceiptField::CumulativeGasUsed)
+            .unwrap();
+        assert_eq!(cumulative_gas_used.value, gas_used_after_refund);
+    }
+}
@@ -389,6 +389,7 @@ impl<const N_ARGS: usize> Opcode for CallOpcode<N_ARGS> {
                             log_id: None,
                             rw_counter_start,
                             bytes: input_bytes.iter().map(|s| (*s, false)).collect(),
+                            is_reverse: false,
                         },
                     );
                     Some(input_bytes)
@@ -415,6 +416,7 @@ impl<const N_ARGS: usize> Opcode for CallOpcode<N_ARGS> {
                             log_id: None,
                             rw_counter_start,
                             bytes: output_bytes.iter().map(|s| (*s, false)).collect(),
+                            is_reverse: false,
                         },
                     );
                     Some(output_bytes)
@@ -444,6 +446,7 @@ impl<const N_ARGS: usize> Opcode for CallOpcode<N_ARGS> {
                             log_id: None,
                             rw_counter_start,
                             bytes: return_bytes.iter().map(|s| (*s, false)).collect(),
+                            is_reverse: false,
                         },
                     );
                     Some(return_bytes)
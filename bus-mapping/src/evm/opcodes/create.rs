@@ -232,6 +232,7 @@ impl<const IS_CREATE2: bool> Opcode for Create<IS_CREATE2> {
                     value_prev: 0.into(),
                 },
             )?;
+            state.tx_created_account_write(&mut exec_step, callee.address)?;
 
             if length > 0 {
                 for (field, value) in [
@@ -324,6 +325,7 @@ fn handle_copy(
             dst_addr: 0,
             log_id: None,
             bytes,
+            is_reverse: false,
         },
     );
 
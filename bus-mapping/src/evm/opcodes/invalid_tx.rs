@@ -40,7 +40,8 @@ impl TxExecSteps for InvalidTx {
         )?;
 
         // Stop processing the tx
-        end_tx(state, &mut exec_step, &call)?;
+        let gas_used = state.tx.gas() - exec_step.gas_left;
+        end_tx(state, &mut exec_step, &call, gas_used)?;
 
         Ok(exec_step)
     }
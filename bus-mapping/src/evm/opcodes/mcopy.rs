@@ -0,0 +1,292 @@
+use super::Opcode;
+use crate::{
+    circuit_input_builder::{
+        CircuitInputStateRef, CopyDataType, CopyEvent, ExecStep, NumberOrHash,
+    },
+    Error,
+};
+use eth_types::GethExecStep;
+
+/// Placeholder structure used to implement [`Opcode`] trait over it
+/// corresponding to the [`OpcodeId::MCOPY`](crate::evm::OpcodeId::MCOPY)
+/// `OpcodeId`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Mcopy;
+
+impl Opcode for Mcopy {
+    fn gen_associated_ops(
+        state: &mut CircuitInputStateRef,
+        geth_steps: &[GethExecStep],
+    ) -> Result<Vec<ExecStep>, Error> {
+        let geth_step = &geth_steps[0];
+        let mut exec_step = state.new_step(geth_step)?;
+
+        let dst_offset = geth_step.stack.nth_last(0)?;
+        let src_offset = geth_step.stack.nth_last(1)?;
+        let length = geth_step.stack.nth_last(2)?;
+        for (idx, value) in [dst_offset, src_offset, length].into_iter().enumerate() {
+            state.stack_read(&mut exec_step, geth_step.stack.nth_last_filled(idx), value)?;
+        }
+
+        // Both the source and the destination range expand the memory.
+        let memory = &mut state.call_ctx_mut()?.memory;
+        memory.extend_for_range(src_offset, length);
+        memory.extend_for_range(dst_offset, length);
+
+        let copy_event = gen_copy_event(state, geth_step, &mut exec_step)?;
+        state.push_copy(&mut exec_step, copy_event);
+        Ok(vec![exec_step])
+    }
+}
+
+fn gen_copy_steps(
+    state: &mut CircuitInputStateRef,
+    exec_step: &mut ExecStep,
+    src_addr: u64,
+    dst_addr: u64,
+    bytes_left: u64,
+    is_reverse: bool,
+) -> Result<Vec<(u8, bool)>, Error> {
+    let mut copy_steps = Vec::with_capacity(bytes_left as usize);
+    for idx in 0..bytes_left {
+        // Each byte is read from the live memory right before it is written.
+        // When the destination starts inside the source range the bytes are
+        // copied from the end, so that no source byte is overwritten before
+        // it is read, which gives the same result as geth's `copy`.
+        let idx = if is_reverse {
+            bytes_left - 1 - idx
+        } else {
+            idx
+        };
+        let value = state.memory_read(exec_step, (src_addr + idx).into())?;
+        state.memory_write(exec_step, (dst_addr + idx).into(), value)?;
+        copy_steps.push((value, false));
+    }
+    Ok(copy_steps)
+}
+
+fn gen_copy_event(
+    state: &mut CircuitInputStateRef,
+    geth_step: &GethExecStep,
+    exec_step: &mut ExecStep,
+) -> Result<CopyEvent, Error> {
+    // Get low Uint64 of offsets to generate copy steps. Since they could be
+    // Uint64 overflow if length is zero.
+    let dst_addr = geth_step.stack.nth_last(0)?.low_u64();
+    let src_addr = geth_step.stack.nth_last(1)?.low_u64();
+    let length = geth_step.stack.nth_last(2)?.as_u64();
+    let is_reverse = src_addr < dst_addr && dst_addr < src_addr + length;
+
+    let rw_counter_start = state.block_ctx.rwc;
+    let copy_steps = gen_copy_steps(state, exec_step, src_addr, dst_addr, length, is_reverse)?;
+
+    let call_id = state.call()?.call_id;
+    Ok(CopyEvent {
+        src_type: CopyDataType::Memory,
+        src_id: NumberOrHash::Number(call_id),
+        src_addr,
+        src_addr_end: src_addr + length,
+        dst_type: CopyDataType::Memory,
+        dst_id: NumberOrHash::Number(call_id),
+        dst_addr,
+        log_id: None,
+        rw_counter_start,
+        bytes: copy_steps,
+        is_reverse,
+    })
+}
+
+#[cfg(test)]
+mod mcopy_tests {
+    use crate::{
+        circuit_input_builder::{ExecState, NumberOrHash},
+        mock::BlockData,
+        operation::{MemoryOp, StackOp, RW},
+    };
+    use eth_types::{
+        bytecode,
+        evm_types::{OpcodeId, StackAddress},
+        geth_types::GethData,
+        Word,
+    };
+    use mock::test_ctx::{helpers::*, TestContext};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn mcopy_opcode_impl() {
+        let (dst_offset, src_offset, length) = (0x10usize, 0x20usize, 0x20usize);
+        let value = Word::from_big_endian(&(1..=32).collect::<Vec<u8>>());
+        let code = bytecode! {
+            PUSH32(value)
+            PUSH1(src_offset)
+            MSTORE
+            PUSH1(length)
+            PUSH1(src_offset)
+            PUSH1(dst_offset)
+            MCOPY
+            STOP
+        };
+
+        // Get the execution steps from the external tracer
+        let block: GethData = TestContext::<2, 1>::new(
+            None,
+            account_0_code_account_1_no_code(code),
+            tx_from_1_to_0,
+            |block, _tx| block,
+        )
+        .unwrap()
+        .into();
+
+        let builder = BlockData::new_from_geth_data(block.clone()).new_circuit_input_builder();
+        let builder = builder
+            .handle_block(&block.eth_block, &block.geth_traces)
+            .unwrap();
+
+        let step = builder.block.txs()[0]
+            .steps()
+            .iter()
+            .find(|step| step.exec_state == ExecState::Op(OpcodeId::MCOPY))
+            .unwrap();
+        let call_id = builder.block.txs()[0].calls()[0].call_id;
+
+        // 3 stack reads + `length` x 2 memory r/w
+        assert_eq!(step.bus_mapping_instance.len(), 3 + length * 2);
+
+        assert_eq!(
+            [0, 1, 2]
+                .map(|idx| &builder.block.container.stack[step.bus_mapping_instance[idx].as_usize()])
+                .map(|operation| (operation.rw(), operation.op())),
+            [
+                (
+                    RW::READ,
+                    &StackOp::new(call_id, StackAddress::from(1021), Word::from(dst_offset))
+                ),
+                (
+                    RW::READ,
+                    &StackOp::new(call_id, StackAddress::from(1022), Word::from(src_offset))
+                ),
+                (
+                    RW::READ,
+                    &StackOp::new(call_id, StackAddress::from(1023), Word::from(length))
+                ),
+            ]
+        );
+
+        // The ranges overlap, but every source byte is read before it is
+        // overwritten.
+        assert_eq!(
+            (0..length * 2)
+                .map(|idx| &builder.block.container.memory
+                    [step.bus_mapping_instance[3 + idx].as_usize()])
+                .map(|operation| (operation.rw(), operation.op().clone()))
+                .collect::<Vec<(RW, MemoryOp)>>(),
+            (0..length)
+                .flat_map(|idx| {
+                    let byte = idx as u8 + 1;
+                    [
+                        (
+                            RW::READ,
+                            MemoryOp::new(call_id, (src_offset + idx).into(), byte),
+                        ),
+                        (
+                            RW::WRITE,
+                            MemoryOp::new(call_id, (dst_offset + idx).into(), byte),
+                        ),
+                    ]
+                })
+                .collect::<Vec<(RW, MemoryOp)>>(),
+        );
+
+        let copy_events = builder.block.copy_events.clone();
+        assert_eq!(copy_events.len(), 1);
+        assert_eq!(copy_events[0].src_id, NumberOrHash::Number(call_id));
+        assert_eq!(copy_events[0].dst_id, NumberOrHash::Number(call_id));
+        assert_eq!(copy_events[0].src_addr as usize, src_offset);
+        assert_eq!(copy_events[0].src_addr_end as usize, src_offset + length);
+        assert_eq!(copy_events[0].dst_addr as usize, dst_offset);
+        assert!(!copy_events[0].is_reverse);
+        assert_eq!(
+            copy_events[0].bytes,
+            (1..=32).map(|value| (value, false)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn mcopy_opcode_overlap_dst_after_src() {
+        let (dst_offset, src_offset, length) = (0x30usize, 0x20usize, 0x20usize);
+        let value = Word::from_big_endian(&(1..=32).collect::<Vec<u8>>());
+        let code = bytecode! {
+            PUSH32(value)
+            PUSH1(src_offset)
+            MSTORE
+            PUSH1(length)
+            PUSH1(src_offset)
+            PUSH1(dst_offset)
+            MCOPY
+            STOP
+        };
+
+        // Get the execution steps from the external tracer
+        let block: GethData = TestContext::<2, 1>::new(
+            None,
+            account_0_code_account_1_no_code(code),
+            tx_from_1_to_0,
+            |block, _tx| block,
+        )
+        .unwrap()
+        .into();
+
+        let builder = BlockData::new_from_geth_data(block.clone()).new_circuit_input_builder();
+        let builder = builder
+            .handle_block(&block.eth_block, &block.geth_traces)
+            .unwrap();
+
+        let step = builder.block.txs()[0]
+            .steps()
+            .iter()
+            .find(|step| step.exec_state == ExecState::Op(OpcodeId::MCOPY))
+            .unwrap();
+        let call_id = builder.block.txs()[0].calls()[0].call_id;
+
+        // The destination starts inside the source range, so the bytes are
+        // copied from the end and every source byte is still read before it
+        // is overwritten.
+        assert_eq!(
+            (0..length * 2)
+                .map(|idx| &builder.block.container.memory
+                    [step.bus_mapping_instance[3 + idx].as_usize()])
+                .map(|operation| (operation.rw(), operation.op().clone()))
+                .collect::<Vec<(RW, MemoryOp)>>(),
+            (0..length)
+                .rev()
+                .flat_map(|idx| {
+                    let byte = idx as u8 + 1;
+                    [
+                        (
+                            RW::READ,
+                            MemoryOp::new(call_id, (src_offset + idx).into(), byte),
+                        ),
+                        (
+                            RW::WRITE,
+                            MemoryOp::new(call_id, (dst_offset + idx).into(), byte),
+                        ),
+                    ]
+                })
+                .collect::<Vec<(RW, MemoryOp)>>(),
+        );
+
+        let copy_events = builder.block.copy_events.clone();
+        assert_eq!(copy_events.len(), 1);
+        assert_eq!(copy_events[0].src_addr as usize, src_offset);
+        assert_eq!(copy_events[0].src_addr_end as usize, src_offset + length);
+        assert_eq!(copy_events[0].dst_addr as usize, dst_offset);
+        assert!(copy_events[0].is_reverse);
+        assert_eq!(
+            copy_events[0].bytes,
+            (1..=32)
+                .rev()
+                .map(|value| (value, false))
+                .collect::<Vec<_>>()
+        );
+    }
+}
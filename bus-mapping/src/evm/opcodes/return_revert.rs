@@ -39,6 +39,17 @@ impl Opcode for ReturnRevert {
             call.is_success.to_word(),
         )?;
 
+        // The rollback of a reverted call takes place right after this step.
+        // The value is set later in `set_value_ops_call_context_rwc_eor`.
+        if !call.is_success {
+            state.call_context_read(
+                &mut exec_step,
+                call.call_id,
+                CallContextField::RwCounterEndOfReversion,
+                call.rw_counter_end_of_reversion.to_word(),
+            )?;
+        }
+
         // Get low Uint64 of offset to generate copy steps. Since offset could
         // be Uint64 overflow if length is zero.
         let offset = offset.low_u64() as usize;
@@ -194,6 +205,7 @@ fn handle_copy(
             dst_addr: destination.offset.try_into().unwrap(),
             log_id: None,
             bytes,
+            is_reverse: false,
         },
     );
 
@@ -232,6 +244,7 @@ fn handle_create(
             dst_addr: 0,
             log_id: None,
             bytes,
+            is_reverse: false,
         },
     );
 
@@ -0,0 +1,368 @@
+use super::Opcode;
+use crate::{
+    circuit_input_builder::{CircuitInputStateRef, ExecStep},
+    operation::{
+        AccountField, AccountOp, CallContextField, TxAccessListAccountOp, TxCreatedAccountOp, RW,
+    },
+    Error,
+};
+use eth_types::{GethExecStep, ToAddress, ToWord, H256, U256};
+
+/// Placeholder structure used to implement [`Opcode`] trait over it
+/// corresponding to the
+/// [`OpcodeId::SELFDESTRUCT`](crate::evm::OpcodeId::SELFDESTRUCT) `OpcodeId`.
+/// The whole balance of the current account is moved to the beneficiary. As
+/// per EIP-6780, the nonce and code hash of the current account are only
+/// cleared when it was created in the same transaction, and otherwise a
+/// beneficiary equal to the current account keeps its balance. All the
+/// account writes are reversible, so they are undone if an enclosing call
+/// reverts.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct SelfDestruct;
+
+impl Opcode for SelfDestruct {
+    fn gen_associated_ops(
+        state: &mut CircuitInputStateRef,
+        geth_steps: &[GethExecStep],
+    ) -> Result<Vec<ExecStep>, Error> {
+        let geth_step = &geth_steps[0];
+        let mut exec_step = state.new_step(geth_step)?;
+        let call = state.call()?.clone();
+
+        // Read the beneficiary address from stack.
+        let receiver_word = geth_step.stack.last()?;
+        let receiver = receiver_word.to_address();
+        state.stack_read(&mut exec_step, geth_step.stack.last_filled(), receiver_word)?;
+
+        for (field, value) in [
+            (CallContextField::TxId, U256::from(state.tx_ctx.id())),
+            (
+                CallContextField::RwCounterEndOfReversion,
+                U256::from(call.rw_counter_end_of_reversion as u64),
+            ),
+            (
+                CallContextField::IsPersistent,
+                U256::from(call.is_persistent as u64),
+            ),
+            (CallContextField::CalleeAddress, call.address.to_word()),
+            // Call ends with SELFDESTRUCT must be successful
+            (CallContextField::IsSuccess, U256::one()),
+        ] {
+            state.call_context_read(&mut exec_step, call.call_id, field, value)?;
+        }
+
+        // Update transaction access list for the beneficiary.
+        let is_warm = state.sdb.check_account_in_access_list(&receiver);
+        state.push_op_reversible(
+            &mut exec_step,
+            TxAccessListAccountOp {
+                tx_id: state.tx_ctx.id(),
+                address: receiver,
+                is_warm: true,
+                is_warm_prev: is_warm,
+            },
+        )?;
+
+        // Read the beneficiary code hash, which is 0 for an empty account.
+        let (found, receiver_account) = state.sdb.get_account(&receiver);
+        if !found {
+            return Err(Error::AccountNotFound(receiver));
+        }
+        let receiver_exists = !receiver_account.is_empty();
+        let receiver_code_hash = if receiver_exists {
+            receiver_account.code_hash
+        } else {
+            H256::zero()
+        };
+        state.account_read(
+            &mut exec_step,
+            receiver,
+            AccountField::CodeHash,
+            receiver_code_hash.to_word(),
+        )?;
+
+        // Read whether the current account was created in this transaction, as recorded
+        // by the creation `BeginTx` or `CREATE*` step.
+        let is_created_in_tx = state.sdb.is_created_account(&call.address);
+        state.push_op(
+            &mut exec_step,
+            RW::READ,
+            TxCreatedAccountOp {
+                tx_id: state.tx_ctx.id(),
+                address: call.address,
+                is_created: is_created_in_tx,
+                is_created_prev: is_created_in_tx,
+            },
+        )?;
+
+        let (found, sender_account) = state.sdb.get_account(&call.address);
+        if !found {
+            return Err(Error::AccountNotFound(call.address));
+        }
+        let sender_account = sender_account.clone();
+        let value = sender_account.balance;
+
+        // The balance leaves the current account, unless the account is the beneficiary
+        // and survives the SELFDESTRUCT.
+        let is_self = receiver == call.address;
+        if !is_self || is_created_in_tx {
+            state.push_op_reversible(
+                &mut exec_step,
+                AccountOp {
+                    address: call.address,
+                    field: AccountField::Balance,
+                    value: U256::zero(),
+                    value_prev: value,
+                },
+            )?;
+        }
+
+        // Only an account created in the current transaction is deleted.
+        if is_created_in_tx {
+            for (field, value_prev) in [
+                (AccountField::Nonce, sender_account.nonce.into()),
+                (AccountField::CodeHash, sender_account.code_hash.to_word()),
+            ] {
+                state.push_op_reversible(
+                    &mut exec_step,
+                    AccountOp {
+                        address: call.address,
+                        field,
+                        value: U256::zero(),
+                        value_prev,
+                    },
+                )?;
+            }
+        }
+
+        // The balance is burnt when the beneficiary is the deleted current account.
+        if !is_self {
+            state.transfer_to(
+                &mut exec_step,
+                receiver,
+                receiver_exists,
+                false,
+                value,
+                true,
+            )?;
+        }
+
+        if call.is_persistent && is_created_in_tx {
+            state.sdb.destruct_account(call.address);
+        }
+
+        state.handle_return(&mut [&mut exec_step], geth_steps, !call.is_root)?;
+        Ok(vec![exec_step])
+    }
+}
+
+#[cfg(test)]
+mod selfdestruct_tests {
+    use super::*;
+    use crate::{
+        circuit_input_builder::ExecState, mock::BlockData, operation::CallContextOp,
+        state_db::CodeDB,
+    };
+    use eth_types::{address, bytecode, evm_types::OpcodeId, geth_types::GethData, Word};
+    use mock::TestContext;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn selfdestruct_opcode_impl() {
+        let contract = address!("0x0000000000000000000000000000000000000010");
+        let beneficiary = address!("0x0000000000000000000000000000000000cafe01");
+        let code = bytecode! {
+            PUSH20(beneficiary.to_word())
+            SELFDESTRUCT
+        };
+
+        // Get the execution steps from the external tracer.
+        let block: GethData = TestContext::<2, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(contract)
+                    .balance(Word::from(1000u64))
+                    .nonce(1)
+                    .code(code);
+                accs[1]
+                    .address(address!("0x0000000000000000000000000000000000000020"))
+                    .balance(Word::from(1u64 << 30));
+            },
+            |mut txs, accs| {
+                txs[0].to(accs[0].address).from(accs[1].address);
+            },
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap()
+        .into();
+
+        let builder = BlockData::new_from_geth_data(block.clone()).new_circuit_input_builder();
+        let builder = builder
+            .handle_block(&block.eth_block, &block.geth_traces)
+            .unwrap();
+
+        let tx_id = 1;
+        let transaction = &builder.block.txs()[tx_id - 1];
+        let call_id = transaction.calls()[0].call_id;
+
+        let indices = transaction
+            .steps()
+            .iter()
+            .find(|step| step.exec_state == ExecState::Op(OpcodeId::SELFDESTRUCT))
+            .unwrap()
+            .bus_mapping_instance
+            .clone();
+
+        let container = builder.block.container;
+
+        let operation = &container.call_context[indices[5].as_usize()];
+        assert_eq!(operation.rw(), RW::READ);
+        assert_eq!(
+            operation.op(),
+            &CallContextOp {
+                call_id,
+                field: CallContextField::IsSuccess,
+                value: U256::one()
+            }
+        );
+
+        let operation = &container.tx_access_list_account[indices[6].as_usize()];
+        assert_eq!(operation.rw(), RW::WRITE);
+        assert_eq!(
+            operation.op(),
+            &TxAccessListAccountOp {
+                tx_id,
+                address: beneficiary,
+                is_warm: true,
+                is_warm_prev: false
+            }
+        );
+
+        let operation = &container.account[indices[7].as_usize()];
+        assert_eq!(operation.rw(), RW::READ);
+        assert_eq!(
+            operation.op(),
+            &AccountOp {
+                address: beneficiary,
+                field: AccountField::CodeHash,
+                value: U256::zero(),
+                value_prev: U256::zero(),
+            }
+        );
+
+        // The contract was not created in this transaction, so only its balance
+        // is cleared.
+        let operation = &container.tx_created_account[indices[8].as_usize()];
+        assert_eq!(operation.rw(), RW::READ);
+        assert_eq!(
+            operation.op(),
+            &TxCreatedAccountOp {
+                tx_id,
+                address: contract,
+                is_created: false,
+                is_created_prev: false,
+            }
+        );
+
+        let operation = &container.account[indices[9].as_usize()];
+        assert_eq!(operation.rw(), RW::WRITE);
+        assert_eq!(
+            operation.op(),
+            &AccountOp {
+                address: contract,
+                field: AccountField::Balance,
+                value: U256::zero(),
+                value_prev: Word::from(1000u64),
+            }
+        );
+
+        for (index, field, value) in [
+            (
+                10,
+                AccountField::CodeHash,
+                CodeDB::empty_code_hash().to_word(),
+            ),
+            (11, AccountField::Balance, Word::from(1000u64)),
+        ] {
+            let operation = &container.account[indices[index].as_usize()];
+            assert_eq!(operation.rw(), RW::WRITE);
+            assert_eq!(
+                operation.op(),
+                &AccountOp {
+                    address: beneficiary,
+                    field,
+                    value,
+                    value_prev: U256::zero(),
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn selfdestruct_keeps_account_not_created_in_tx() {
+        let contract = address!("0x0000000000000000000000000000000000000010");
+        let caller = address!("0x0000000000000000000000000000000000000020");
+        let code = bytecode! {
+            ADDRESS
+            SELFDESTRUCT
+        };
+        let code_hash = CodeDB::hash(&code.code());
+        let call_contract = bytecode! {
+            PUSH1(0)
+            PUSH1(0)
+            PUSH1(0)
+            PUSH1(0)
+            PUSH1(0)
+            PUSH20(contract.to_word())
+            GAS
+            CALL
+            POP
+        };
+        let mut caller_code = call_contract.clone();
+        caller_code.append(&call_contract);
+        caller_code.append(&bytecode! {
+            STOP
+        });
+
+        let block: GethData = TestContext::<3, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(address!("0x0000000000000000000000000000000000000123"))
+                    .balance(Word::from(1u64 << 30));
+                accs[1].address(caller).code(caller_code);
+                accs[2]
+                    .address(contract)
+                    .balance(Word::from(1000u64))
+                    .nonce(1)
+                    .code(code);
+            },
+            |mut txs, accs| {
+                txs[0].to(accs[1].address).from(accs[0].address);
+            },
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap()
+        .into();
+
+        let builder = BlockData::new_from_geth_data(block.clone()).new_circuit_input_builder();
+        let builder = builder
+            .handle_block(&block.eth_block, &block.geth_traces)
+            .unwrap();
+
+        // The second call still finds the code of the contract.
+        let num_selfdestructs = builder.block.txs()[0]
+            .steps()
+            .iter()
+            .filter(|step| step.exec_state == ExecState::Op(OpcodeId::SELFDESTRUCT))
+            .count();
+        assert_eq!(num_selfdestructs, 2);
+
+        let (found, account) = builder.sdb.get_account(&contract);
+        assert!(found);
+        assert_eq!(account.code_hash, code_hash);
+        assert_eq!(account.nonce, 1);
+        assert_eq!(account.balance, Word::from(1000u64));
+    }
+}
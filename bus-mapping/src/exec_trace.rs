@@ -26,6 +26,7 @@ impl fmt::Debug for OperationRef {
                 Target::CallContext => "CallContext",
                 Target::TxReceipt => "TxReceipt",
                 Target::TxLog => "TxLog",
+                Target::TxCreatedAccount => "TxCreatedAccount",
                 Target::StepState => "StepState",
             },
             self.1
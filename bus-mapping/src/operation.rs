@@ -118,6 +118,8 @@ pub enum Target {
     TxReceipt,
     /// Means the target of the operation is the TxLog.
     TxLog,
+    /// Means the target of the operation is the TxCreatedAccount.
+    TxCreatedAccount,
 
     /// Chunking: StepState
     StepState,
@@ -141,6 +143,7 @@ impl Target {
             Target::TxAccessListAccount
                 | Target::TxAccessListAccountStorage
                 | Target::TxRefund
+                | Target::TxCreatedAccount
                 | Target::Account
                 | Target::Storage
                 | Target::TransientStorage
@@ -561,6 +564,56 @@ impl Op for TxAccessListAccountOp {
     }
 }
 
+/// Represents an account created in the transaction by a creation `BeginTx`
+/// or a `CREATE*` step, and read by `SELFDESTRUCT` to apply EIP-6780.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TxCreatedAccountOp {
+    /// Transaction ID: Transaction index in the block starting at 1.
+    pub tx_id: usize,
+    /// Account Address
+    pub address: Address,
+    /// Whether the account was created in the transaction.
+    pub is_created: bool,
+    /// Whether the account was created in the transaction before this
+    /// operation.
+    pub is_created_prev: bool,
+}
+
+impl fmt::Debug for TxCreatedAccountOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TxCreatedAccountOp { ")?;
+        f.write_fmt(format_args!(
+            "tx_id: {:?}, addr: {:?}, is_created_prev: {:?}, is_created: {:?}",
+            self.tx_id, self.address, self.is_created_prev, self.is_created
+        ))?;
+        f.write_str(" }")
+    }
+}
+
+impl PartialOrd for TxCreatedAccountOp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TxCreatedAccountOp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.tx_id, &self.address).cmp(&(&other.tx_id, &other.address))
+    }
+}
+
+impl Op for TxCreatedAccountOp {
+    fn into_enum(self) -> OpEnum {
+        OpEnum::TxCreatedAccount(self)
+    }
+
+    fn reverse(&self) -> Self {
+        let mut rev = self.clone();
+        swap(&mut rev.is_created, &mut rev.is_created_prev);
+        rev
+    }
+}
+
 /// Represents a change in the Storage AccessList implied by an `SSTORE` or
 /// `SLOAD` step of the [`ExecStep`](crate::circuit_input_builder::ExecStep).
 #[derive(Clone, PartialEq, Eq)]
@@ -1171,6 +1224,8 @@ pub enum OpEnum {
     TxReceipt(TxReceiptOp),
     /// TxLog
     TxLog(TxLogOp),
+    /// TxCreatedAccount
+    TxCreatedAccount(TxCreatedAccountOp),
     /// Start
     Start(StartOp),
     /// Padding
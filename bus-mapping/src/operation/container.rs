@@ -1,7 +1,7 @@
 use super::{
     AccountOp, CallContextOp, MemoryOp, Op, OpEnum, Operation, PaddingOp, RWCounter, StackOp,
     StartOp, StepStateOp, StorageOp, Target, TransientStorageOp, TxAccessListAccountOp,
-    TxAccessListAccountStorageOp, TxLogOp, TxReceiptOp, TxRefundOp, RW,
+    TxAccessListAccountStorageOp, TxCreatedAccountOp, TxLogOp, TxReceiptOp, TxRefundOp, RW,
 };
 use crate::exec_trace::OperationRef;
 use itertools::Itertools;
@@ -44,6 +44,8 @@ pub struct OperationContainer {
     pub tx_receipt: Vec<Operation<TxReceiptOp>>,
     /// Operations of TxLogOp
     pub tx_log: Vec<Operation<TxLogOp>>,
+    /// Operations of TxCreatedAccountOp
+    pub tx_created_account: Vec<Operation<TxCreatedAccountOp>>,
     /// Operations of Start
     pub start: Vec<Operation<StartOp>>,
     /// Operations of Padding
@@ -74,6 +76,7 @@ impl OperationContainer {
             call_context: Vec::new(),
             tx_receipt: Vec::new(),
             tx_log: Vec::new(),
+            tx_created_account: Vec::new(),
             start: Vec::new(),
             padding: Vec::new(),
             step_state: Vec::new(),
@@ -184,6 +187,14 @@ impl OperationContainer {
                     .push(Operation::new(rwc, rwc_inner_chunk, rw, op));
                 OperationRef::from((Target::TxLog, self.tx_log.len() - 1))
             }
+            OpEnum::TxCreatedAccount(op) => {
+                self.tx_created_account.push(if reversible {
+                    Operation::new_reversible(rwc, rwc_inner_chunk, rw, op)
+                } else {
+                    Operation::new(rwc, rwc_inner_chunk, rw, op)
+                });
+                OperationRef::from((Target::TxCreatedAccount, self.tx_created_account.len() - 1))
+            }
             OpEnum::Start(op) => {
                 self.start
                     .push(Operation::new(rwc, rwc_inner_chunk, rw, op));
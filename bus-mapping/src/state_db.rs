@@ -145,6 +145,9 @@ pub struct StateDB {
     // Accounts that have been through `SELFDESTRUCT` under the situation that `is_persistent` is
     // `true`. These accounts will be reset once `commit_tx` is called.
     destructed_account: HashSet<Address>,
+    // Accounts created in the current transaction. Since EIP-6780 only these are deleted by
+    // `SELFDESTRUCT`.
+    created_account: HashSet<Address>,
     refund: u64,
 }
 
@@ -305,6 +308,22 @@ impl StateDB {
         self.destructed_account.insert(addr);
     }
 
+    /// Set account as created in the current transaction.
+    pub fn add_created_account(&mut self, addr: Address) {
+        self.created_account.insert(addr);
+    }
+
+    /// Remove `addr` from the accounts created in the current transaction.
+    pub fn remove_created_account(&mut self, addr: &Address) {
+        let exist = self.created_account.remove(addr);
+        debug_assert!(exist);
+    }
+
+    /// Check whether the account was created in the current transaction.
+    pub fn is_created_account(&self, addr: &Address) -> bool {
+        self.created_account.contains(addr)
+    }
+
     /// Retrieve refund.
     pub fn refund(&self) -> u64 {
         self.refund
@@ -330,6 +349,7 @@ impl StateDB {
             let (_, account) = self.get_account_mut(&addr);
             *account = ACCOUNT_ZERO.clone();
         }
+        self.created_account = HashSet::new();
         self.refund = 0;
     }
 
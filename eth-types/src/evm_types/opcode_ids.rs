@@ -291,6 +291,8 @@ pub enum OpcodeId {
     TLOAD,
     /// `TSTORE`
     TSTORE,
+    /// `MCOPY`
+    MCOPY,
 
     // LOGn
     /// `LOG0`
@@ -507,6 +509,7 @@ impl OpcodeId {
             OpcodeId::GAS => 0x5au8,
             OpcodeId::TLOAD => 0x5cu8,
             OpcodeId::TSTORE => 0x5du8,
+            OpcodeId::MCOPY => 0x5eu8,
             OpcodeId::LOG0 => 0xa0u8,
             OpcodeId::LOG1 => 0xa1u8,
             OpcodeId::LOG2 => 0xa2u8,
@@ -596,6 +599,7 @@ impl OpcodeId {
             OpcodeId::JUMPDEST => GasCost::ONE,
             OpcodeId::TLOAD => GasCost::WARM_ACCESS,
             OpcodeId::TSTORE => GasCost::WARM_ACCESS,
+            OpcodeId::MCOPY => GasCost::FASTEST,
             OpcodeId::PUSH0 => GasCost::QUICK,
             OpcodeId::PUSH1 => GasCost::FASTEST,
             OpcodeId::PUSH2 => GasCost::FASTEST,
@@ -753,6 +757,7 @@ impl OpcodeId {
             OpcodeId::JUMPDEST => (0, 1024),
             OpcodeId::TLOAD => (0, 1023),
             OpcodeId::TSTORE => (0, 1022),
+            OpcodeId::MCOPY => (0, 1021),
             OpcodeId::PUSH0 => (1, 1024),
             OpcodeId::PUSH1 => (1, 1024),
             OpcodeId::PUSH2 => (1, 1024),
@@ -855,6 +860,7 @@ impl OpcodeId {
                 | OpcodeId::RETURNDATACOPY
                 | OpcodeId::CODECOPY
                 | OpcodeId::EXTCODECOPY
+                | OpcodeId::MCOPY
         )
     }
 
@@ -960,6 +966,7 @@ impl From<u8> for OpcodeId {
             0x5bu8 => OpcodeId::JUMPDEST,
             0x5cu8 => OpcodeId::TLOAD,
             0x5du8 => OpcodeId::TSTORE,
+            0x5eu8 => OpcodeId::MCOPY,
             0x5fu8 => OpcodeId::PUSH0,
             0x60u8 => OpcodeId::PUSH1,
             0x61u8 => OpcodeId::PUSH2,
@@ -1220,6 +1227,7 @@ impl FromStr for OpcodeId {
             "BASEFEE" => OpcodeId::BASEFEE,
             "TLOAD" => OpcodeId::TLOAD,
             "TSTORE" => OpcodeId::TSTORE,
+            "MCOPY" => OpcodeId::MCOPY,
             _ => {
                 // Parse an invalid opcode value as reported by geth
                 lazy_static! {
@@ -130,6 +130,7 @@ impl<F: Field> SubCircuitConfig<F> for CopyCircuitConfig<F> {
         let rlc_acc = copy_table.rlc_acc;
         let rw_counter = copy_table.rw_counter;
         let rwc_inc_left = copy_table.rwc_inc_left;
+        let is_reverse = copy_table.is_reverse;
         let tag_bits = copy_table.tag;
 
         // annotate table columns
@@ -157,6 +158,10 @@ impl<F: Field> SubCircuitConfig<F> for CopyCircuitConfig<F> {
                 "is_last is boolean",
                 meta.query_advice(is_last, Rotation::cur()),
             );
+            cb.require_boolean(
+                "is_reverse is boolean",
+                meta.query_advice(is_reverse, Rotation::cur()),
+            );
             cb.require_zero(
                 "is_first == 0 when q_step == 0",
                 and::expr([
@@ -194,8 +199,9 @@ impl<F: Field> SubCircuitConfig<F> for CopyCircuitConfig<F> {
                         tag.value(Rotation(2))(meta),
                     );
                     cb.require_equal(
-                        "rows[0].addr + 1 == rows[2].addr",
-                        meta.query_advice(addr, Rotation::cur()) + 1.expr(),
+                        "rows[0].addr + 1 - 2 * rows[0].is_reverse == rows[2].addr",
+                        meta.query_advice(addr, Rotation::cur()) + 1.expr()
+                            - 2.expr() * meta.query_advice(is_reverse, Rotation::cur()),
                         meta.query_advice(addr, Rotation(2)),
                     );
                     cb.require_equal(
@@ -231,6 +237,11 @@ impl<F: Field> SubCircuitConfig<F> for CopyCircuitConfig<F> {
                         meta.query_advice(rlc_acc, Rotation::cur()),
                         meta.query_advice(rlc_acc, Rotation::next()),
                     );
+                    cb.require_equal(
+                        "rows[0].is_reverse == rows[1].is_reverse",
+                        meta.query_advice(is_reverse, Rotation::cur()),
+                        meta.query_advice(is_reverse, Rotation::next()),
+                    );
                 },
             );
             cb.condition(meta.query_advice(is_last, Rotation::cur()), |cb| {
@@ -585,7 +596,7 @@ impl<F: Field> CopyCircuitConfig<F> {
                     region,
                     *offset,
                     Value::known(F::from(
-                        copy_event.src_addr + u64::try_from(step_idx).unwrap() / 2u64,
+                        copy_event.src_addr + copy_event.addr_offset(step_idx),
                     )),
                     Value::known(F::from(copy_event.src_addr_end)),
                 )?;
@@ -769,6 +780,13 @@ impl<F: Field> CopyCircuitConfig<F> {
             *offset,
             || Value::known(F::ZERO),
         )?;
+        // is_reverse
+        region.assign_advice(
+            || format!("assign is_reverse {}", *offset),
+            self.copy_table.is_reverse,
+            *offset,
+            || Value::known(F::ZERO),
+        )?;
         // tag
         tag_chip.assign(region, *offset, &CopyDataType::Padding)?;
         // Assign LT gadget
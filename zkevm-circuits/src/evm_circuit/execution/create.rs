@@ -349,6 +349,15 @@ impl<F: Field, const IS_CREATE2: bool, const S: ExecutionState> ExecutionGadget<
                     Some(&mut callee_reversion_info),
                 );
 
+                // Record the contract as created in this transaction for SELFDESTRUCT
+                cb.tx_created_account_write(
+                    tx_id.expr(),
+                    contract_addr.to_word(),
+                    1.expr(),
+                    0.expr(),
+                    Some(&mut callee_reversion_info),
+                );
+
                 cb.condition(init_code.has_length(), |cb| {
                     for (field_tag, value) in [
                         (
@@ -405,7 +414,7 @@ impl<F: Field, const IS_CREATE2: bool, const S: ExecutionState> ExecutionGadget<
                         code_hash: To(create.code_hash()),
                         gas_left: To(callee_gas_left),
                         reversible_write_counter: To(
-                            1.expr() + transfer.reversible_w_delta().expr()
+                            2.expr() + transfer.reversible_w_delta().expr()
                         ),
                         ..StepStateTransition::new_context()
                     })
@@ -430,7 +439,7 @@ impl<F: Field, const IS_CREATE2: bool, const S: ExecutionState> ExecutionGadget<
                         stack_pointer: Delta(2.expr() + is_create2.expr()),
                         gas_left: Delta(-gas_cost.expr()),
                         reversible_write_counter: Delta(
-                            3.expr() + transfer.reversible_w_delta().expr(),
+                            4.expr() + transfer.reversible_w_delta().expr(),
                         ),
                         ..Default::default()
                     })
@@ -706,6 +715,7 @@ impl<F: Field, const IS_CREATE2: bool, const S: ExecutionState> ExecutionGadget<
                 F::ONE
             } else {
                 rws.next(); // callee nonce += 1
+                rws.next(); // callee created in tx
                 rws.next(); // caller id
                 let rw = rws.next();
                 debug_assert_eq!(rw.tag(), Target::CallContext);
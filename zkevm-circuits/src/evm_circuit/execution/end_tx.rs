@@ -68,6 +68,8 @@ impl<F: Field> ExecutionGadget<F> for EndTxGadget<F> {
         let refund = cb.query_cell();
         cb.tx_refund_read(tx_id.expr(), WordLoHi::from_lo_unchecked(refund.expr()));
         let effective_refund = cb.min_max(max_refund.quotient(), refund.expr());
+        // The refunded gas is neither paid to the coinbase nor counted as used.
+        let gas_used_after_refund = gas_used - effective_refund.min();
 
         // Add effective_refund * tx_gas_price back to caller's balance
         let mul_gas_price_by_refund = MulWordByU64Gadget::construct(
@@ -81,7 +83,7 @@ impl<F: Field> ExecutionGadget<F> for EndTxGadget<F> {
             None,
         );
 
-        // Add gas_used * effective_tip to coinbase's balance
+        // Add gas_used_after_refund * effective_tip to coinbase's balance
         let coinbase = cb.query_word_unchecked();
         let coinbase_code_hash = cb.query_word_unchecked();
         let coinbase_code_hash_is_zero = cb.is_zero_word(&coinbase_code_hash);
@@ -102,7 +104,7 @@ impl<F: Field> ExecutionGadget<F> for EndTxGadget<F> {
         let sub_gas_price_by_base_fee =
             AddWordsGadget::construct(cb, [effective_tip.clone(), base_fee], tx_gas_price);
         let mul_effective_tip_by_gas_used =
-            MulWordByU64Gadget::construct(cb, effective_tip, gas_used.clone());
+            MulWordByU64Gadget::construct(cb, effective_tip, gas_used_after_refund.clone());
         let coinbase_reward = TransferToGadget::construct(
             cb,
             coinbase.to_word(),
@@ -116,7 +118,7 @@ impl<F: Field> ExecutionGadget<F> for EndTxGadget<F> {
             cb,
             tx_id.expr(),
             is_persistent.expr(),
-            gas_used,
+            gas_used_after_refund,
             9.expr() + coinbase_reward.rw_delta(),
         );
 
@@ -189,7 +191,8 @@ impl<F: Field> ExecutionGadget<F> for EndTxGadget<F> {
             caller_balance,
         )?;
         let effective_tip = tx.gas_price - block.context.base_fee;
-        let coinbase_reward = effective_tip * gas_used;
+        let gas_used_after_refund = gas_used - effective_refund;
+        let coinbase_reward = effective_tip * gas_used_after_refund;
         self.sub_gas_price_by_base_fee.assign(
             region,
             offset,
@@ -200,7 +203,7 @@ impl<F: Field> ExecutionGadget<F> for EndTxGadget<F> {
             region,
             offset,
             effective_tip,
-            gas_used,
+            gas_used_after_refund,
             coinbase_reward,
         )?;
         self.coinbase
@@ -231,8 +234,15 @@ impl<F: Field> ExecutionGadget<F> for EndTxGadget<F> {
 #[cfg(test)]
 mod test {
 
-    use crate::{table::CallContextFieldTag, test_util::CircuitTestBuilder};
-    use bus_mapping::{circuit_input_builder::FixedCParams, operation::Target};
+    use crate::{
+        table::{CallContextFieldTag, TxReceiptFieldTag},
+        test_util::CircuitTestBuilder,
+        witness::Rw,
+    };
+    use bus_mapping::{
+        circuit_input_builder::{ExecState, FixedCParams},
+        operation::Target,
+    };
     use eth_types::{self, bytecode, Word};
     use itertools::Itertools;
     use mock::{
@@ -491,4 +501,76 @@ mod test {
             .unwrap(),
         );
     }
+
+    fn sstore_clear_ctx() -> TestContext<2, 1> {
+        // Clearing a non-zero storage slot earns a refund.
+        TestContext::<2, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(MOCK_ACCOUNTS[0])
+                    .balance(Word::from(10u64.pow(19)))
+                    .code(bytecode! {
+                        PUSH1(0x00)
+                        PUSH1(0x00)
+                        SSTORE
+                        STOP
+                    })
+                    .storage(vec![(Word::zero(), Word::from(0x6f))].into_iter());
+                accs[1]
+                    .address(MOCK_ACCOUNTS[1])
+                    .balance(Word::from(10u64.pow(19)));
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .to(accs[0].address)
+                    .from(accs[1].address)
+                    .gas_price(gwei(2));
+            },
+            |block, _tx| block,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn end_tx_gadget_sstore_clear_refund() {
+        test_ok(sstore_clear_ctx());
+    }
+
+    #[test]
+    fn end_tx_gadget_receipt_counts_refund_fails() {
+        CircuitTestBuilder::new_from_test_ctx(sstore_clear_ctx())
+            .block_modifier(Box::new(|block, _chunk| {
+                // Count the refunded gas as used in the receipt.
+                let end_tx = block.txs[0]
+                    .steps()
+                    .iter()
+                    .find(|step| step.exec_state == ExecState::EndTx)
+                    .unwrap();
+                let gas_used = block.txs[0].gas() - end_tx.gas_left;
+                let op_ref = (0..end_tx.rw_indices_len())
+                    .map(|i| end_tx.rw_index(i))
+                    .find(|op_ref| {
+                        op_ref.target() == Target::TxReceipt
+                            && matches!(
+                                block.rws[*op_ref],
+                                Rw::TxReceipt {
+                                    field_tag: TxReceiptFieldTag::CumulativeGasUsed,
+                                    ..
+                                }
+                            )
+                    })
+                    .unwrap();
+                match &mut block.rws.0.get_mut(&Target::TxReceipt).unwrap()[op_ref.as_usize()] {
+                    Rw::TxReceipt { value, .. } => {
+                        assert!(*value < gas_used);
+                        *value = gas_used;
+                    }
+                    _ => unreachable!(),
+                }
+            }))
+            .run_with_result()
+            .unwrap_err()
+            .assert_evm_failure()
+    }
 }
@@ -68,8 +68,13 @@ impl<F: Field> ExecutionGadget<F> for ErrorInvalidOpcodeGadget<F> {
 
 #[cfg(test)]
 mod test {
-    use crate::{evm_circuit::test::rand_bytes, test_util::CircuitTestBuilder};
+    use crate::{
+        evm_circuit::test::rand_bytes,
+        test_util::{CircuitTestBuilder, CircuitTestError},
+    };
+    use bus_mapping::circuit_input_builder::ExecState;
     use eth_types::{bytecode::Bytecode, Word};
+    use halo2_proofs::dev::VerifyFailure;
     use lazy_static::lazy_static;
     use mock::{generate_mock_call_bytecode, MockCallBytecodeParams, TestContext};
 
@@ -82,7 +87,7 @@ mod test {
             vec![0xf6],
             vec![0xfe],
             // Multiple invalid opcodes
-            vec![0x5c, 0x5e],
+            vec![0x0c, 0x0d],
         ];
     }
 
@@ -93,6 +98,51 @@ mod test {
         }
     }
 
+    #[test]
+    fn invalid_opcode_root_refunding_gas_fails() {
+        let mut code = Bytecode::default();
+        code.write(0xfe, true);
+
+        let err = CircuitTestBuilder::new_from_test_ctx(
+            TestContext::<2, 1>::simple_ctx_with_bytecode(code).unwrap(),
+        )
+        .block_modifier(Box::new(|block, _chunk| {
+            // The root call halts in exception, so no gas is left for EndTx
+            // to refund. Pretend some of it was kept.
+            let end_tx = block.txs[0]
+                .steps_mut()
+                .iter_mut()
+                .find(|step| step.exec_state == ExecState::EndTx)
+                .unwrap();
+            assert_eq!(end_tx.gas_left, 0);
+            end_tx.gas_left = 1;
+        }))
+        .run_with_result()
+        .unwrap_err();
+        err.assert_evm_failure();
+
+        // EndTx also fails on the caller refund and the receipt, which no
+        // longer match the witness, so check the transition out of the
+        // halting step is what rejects the kept gas.
+        let reasons = match err {
+            CircuitTestError::VerificationFailed { reasons, .. } => reasons,
+            _ => unreachable!(),
+        };
+        assert!(
+            reasons.iter().any(|reason| match reason {
+                VerifyFailure::ConstraintNotSatisfied { constraint, .. } => {
+                    // fields of halo2_proofs::dev::metadata::Constraint aren't public, so we
+                    // have to match off of its format string.
+                    let constraint = format!("{constraint}");
+                    constraint.contains("State transition (to) constraint of gas_left")
+                        && constraint.contains("ErrorInvalidOpcode")
+                }
+                _ => false,
+            }),
+            "gas_left transition of ErrorInvalidOpcode is not violated: {reasons:#?}"
+        );
+    }
+
     #[test]
     fn invalid_opcode_internal() {
         for invalid_code in TESTING_INVALID_CODES.iter() {
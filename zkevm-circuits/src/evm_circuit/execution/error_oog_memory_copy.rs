@@ -11,13 +11,13 @@ use crate::{
                 CommonMemoryAddressGadget, MemoryCopierGasGadget, MemoryExpandedAddressGadget,
                 MemoryExpansionGadget,
             },
-            or, select, AccountAddress, CachedRegion, Cell, StepRws,
+            not, or, select, AccountAddress, CachedRegion, Cell, StepRws,
         },
         witness::{Block, Call, Chunk, ExecStep, Transaction},
     },
     table::CallContextFieldTag,
     util::{
-        word::{WordExpr, WordLoHi, WordLoHiCell},
+        word::{WordExpr, WordLoHi},
         Expr,
     },
 };
@@ -29,7 +29,8 @@ use halo2_proofs::{circuit::Value, plonk::Error};
 
 /// Gadget to implement the corresponding out of gas errors for
 /// [`OpcodeId::CALLDATACOPY`], [`OpcodeId::CODECOPY`],
-/// [`OpcodeId::EXTCODECOPY`] and [`OpcodeId::RETURNDATACOPY`].
+/// [`OpcodeId::EXTCODECOPY`], [`OpcodeId::RETURNDATACOPY`] and
+/// [`OpcodeId::MCOPY`].
 #[derive(Clone, Debug)]
 pub(crate) struct ErrorOOGMemoryCopyGadget<F> {
     opcode: Cell<F>,
@@ -38,14 +39,16 @@ pub(crate) struct ErrorOOGMemoryCopyGadget<F> {
     tx_id: Cell<F>,
     /// Extra stack pop for `EXTCODECOPY`
     external_address: AccountAddress<F>,
-    /// Source offset
-    src_offset: WordLoHiCell<F>,
+    /// Source offset, and size to copy which is only non-zero for `MCOPY`
+    /// since the source range of other opcodes is not in memory
+    src_memory_addr: MemoryExpandedAddressGadget<F>,
     /// Destination offset and size to copy
     dst_memory_addr: MemoryExpandedAddressGadget<F>,
-    memory_expansion: MemoryExpansionGadget<F, 1, N_BYTES_MEMORY_WORD_SIZE>,
+    memory_expansion: MemoryExpansionGadget<F, 2, N_BYTES_MEMORY_WORD_SIZE>,
     memory_copier_gas: MemoryCopierGasGadget<F, { GasCost::COPY }>,
     insufficient_gas: LtGadget<F, N_BYTES_GAS>,
     is_extcodecopy: IsZeroGadget<F>,
+    is_mcopy: IsZeroGadget<F>,
     common_error_gadget: CommonErrorGadget<F>,
 }
 
@@ -57,23 +60,24 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGMemoryCopyGadget<F> {
     fn configure(cb: &mut EVMConstraintBuilder<F>) -> Self {
         let opcode = cb.query_cell();
         cb.require_in_set(
-            "ErrorOutOfGasMemoryCopy opcode must be CALLDATACOPY, CODECOPY, EXTCODECOPY or RETURNDATACOPY",
+            "ErrorOutOfGasMemoryCopy opcode must be CALLDATACOPY, CODECOPY, EXTCODECOPY, RETURNDATACOPY or MCOPY",
             opcode.expr(),
             vec![
                 OpcodeId::CALLDATACOPY.expr(),
                 OpcodeId::CODECOPY.expr(),
                 OpcodeId::EXTCODECOPY.expr(),
                 OpcodeId::RETURNDATACOPY.expr(),
+                OpcodeId::MCOPY.expr(),
             ],
         );
 
-        let src_offset = cb.query_word_unchecked();
         let external_address = cb.query_account_address();
         let is_warm = cb.query_bool();
         let tx_id = cb.query_cell();
 
         let is_extcodecopy =
             IsZeroGadget::construct(cb, opcode.expr() - OpcodeId::EXTCODECOPY.expr());
+        let is_mcopy = IsZeroGadget::construct(cb, opcode.expr() - OpcodeId::MCOPY.expr());
 
         cb.condition(is_extcodecopy.expr(), |cb| {
             cb.call_context_lookup_read(
@@ -90,11 +94,31 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGMemoryCopyGadget<F> {
         });
 
         let dst_memory_addr = MemoryExpandedAddressGadget::construct_self(cb);
+        let src_memory_addr = MemoryExpandedAddressGadget::construct_self(cb);
         cb.stack_pop(dst_memory_addr.offset_word());
-        cb.stack_pop(src_offset.to_word());
+        cb.stack_pop(src_memory_addr.offset_word());
         cb.stack_pop(dst_memory_addr.length_word());
 
-        let memory_expansion = MemoryExpansionGadget::construct(cb, [dst_memory_addr.address()]);
+        // MCOPY also expands memory for its source range, which has the same
+        // size as the destination one.
+        cb.condition(is_mcopy.expr(), |cb| {
+            cb.require_equal_word(
+                "MCOPY source and destination have the same size",
+                src_memory_addr.length_word(),
+                dst_memory_addr.length_word(),
+            );
+        });
+        cb.condition(not::expr(is_mcopy.expr()), |cb| {
+            cb.require_zero_word(
+                "Source range is not in memory for opcodes other than MCOPY",
+                src_memory_addr.length_word(),
+            );
+        });
+
+        let memory_expansion = MemoryExpansionGadget::construct(
+            cb,
+            [dst_memory_addr.address(), src_memory_addr.address()],
+        );
         let memory_copier_gas = MemoryCopierGasGadget::construct(
             cb,
             dst_memory_addr.length(),
@@ -110,7 +134,7 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGMemoryCopyGadget<F> {
                 GasCost::WARM_ACCESS.expr(),
                 GasCost::COLD_ACCOUNT_ACCESS.expr(),
             ),
-            // Constant gas cost is same for CALLDATACOPY, CODECOPY and RETURNDATACOPY.
+            // Constant gas cost is same for CALLDATACOPY, CODECOPY, RETURNDATACOPY and MCOPY.
             OpcodeId::CALLDATACOPY.constant_gas_cost().expr(),
         );
 
@@ -122,7 +146,11 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGMemoryCopyGadget<F> {
 
         cb.require_equal(
             "Memory address is overflow or gas left is less than cost",
-            or::expr([dst_memory_addr.overflow(), insufficient_gas.expr()]),
+            or::expr([
+                dst_memory_addr.overflow(),
+                src_memory_addr.overflow(),
+                insufficient_gas.expr(),
+            ]),
             1.expr(),
         );
 
@@ -134,12 +162,13 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGMemoryCopyGadget<F> {
             is_warm,
             tx_id,
             external_address,
-            src_offset,
+            src_memory_addr,
             dst_memory_addr,
             memory_expansion,
             memory_copier_gas,
             insufficient_gas,
             is_extcodecopy,
+            is_mcopy,
             common_error_gadget,
         }
     }
@@ -156,6 +185,7 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGMemoryCopyGadget<F> {
     ) -> Result<(), Error> {
         let opcode = step.opcode().unwrap();
         let is_extcodecopy = opcode == OpcodeId::EXTCODECOPY;
+        let is_mcopy = opcode == OpcodeId::MCOPY;
 
         let mut rws = StepRws::new(block, step);
 
@@ -188,13 +218,21 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGMemoryCopyGadget<F> {
             .assign(region, offset, Value::known(F::from(transaction.id)))?;
         self.external_address
             .assign_h160(region, offset, external_address.to_address())?;
-        self.src_offset.assign_u256(region, offset, src_offset)?;
-        let memory_addr = self
+        let src_memory_addr = self.src_memory_addr.assign(
+            region,
+            offset,
+            src_offset,
+            if is_mcopy { copy_size } else { U256::zero() },
+        )?;
+        let dst_memory_addr = self
             .dst_memory_addr
             .assign(region, offset, dst_offset, copy_size)?;
-        let (_, memory_expansion_cost) =
-            self.memory_expansion
-                .assign(region, offset, step.memory_word_size(), [memory_addr])?;
+        let (_, memory_expansion_cost) = self.memory_expansion.assign(
+            region,
+            offset,
+            step.memory_word_size(),
+            [dst_memory_addr, src_memory_addr],
+        )?;
         let memory_copier_gas = self.memory_copier_gas.assign(
             region,
             offset,
@@ -221,6 +259,11 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGMemoryCopyGadget<F> {
             offset,
             F::from(opcode.as_u64()) - F::from(OpcodeId::EXTCODECOPY.as_u64()),
         )?;
+        self.is_mcopy.assign(
+            region,
+            offset,
+            F::from(opcode.as_u64()) - F::from(OpcodeId::MCOPY.as_u64()),
+        )?;
         self.common_error_gadget.assign(
             region,
             offset,
@@ -290,6 +333,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_oog_memory_copy_for_mcopy() {
+        for (src_offset, (dst_offset, copy_size)) in [0, 0x3000]
+            .iter()
+            .cartesian_product(TESTING_DST_OFFSET_COPY_SIZE_PAIRS.iter())
+        {
+            let testing_data =
+                TestingData::new_for_mcopy(*dst_offset, *src_offset, *copy_size, None);
+
+            test_root(&testing_data);
+            test_internal(&testing_data);
+        }
+    }
+
     #[test]
     fn test_oog_memory_copy_max_expanded_address() {
         // 0xffffffff1 + 0xffffffff0 = 0x1fffffffe1
@@ -377,6 +434,35 @@ mod tests {
 
             Self { bytecode, gas_cost }
         }
+
+        pub fn new_for_mcopy(
+            dst_offset: u64,
+            src_offset: u64,
+            copy_size: u64,
+            gas_cost: Option<u64>,
+        ) -> Self {
+            let bytecode = bytecode! {
+                PUSH32(copy_size)
+                PUSH32(src_offset)
+                PUSH32(dst_offset)
+                MCOPY
+            };
+
+            let gas_cost = gas_cost.unwrap_or_else(|| {
+                // Both the source and the destination range expand memory.
+                let memory_word_size = if copy_size == 0 {
+                    0
+                } else {
+                    (dst_offset.max(src_offset) + copy_size + 31) / 32
+                };
+
+                OpcodeId::PUSH32.constant_gas_cost() * 3
+                    + OpcodeId::MCOPY.constant_gas_cost()
+                    + memory_copier_gas_cost(0, memory_word_size, copy_size)
+            });
+
+            Self { bytecode, gas_cost }
+        }
     }
 
     fn test_root(testing_data: &TestingData) {
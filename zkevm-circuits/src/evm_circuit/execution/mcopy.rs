@@ -0,0 +1,280 @@
+use crate::{
+    evm_circuit::{
+        execution::ExecutionGadget,
+        param::{N_BYTES_MEMORY_WORD_SIZE, N_BYTES_U64},
+        step::ExecutionState,
+        util::{
+            common_gadget::SameContextGadget,
+            constraint_builder::{
+                ConstrainBuilderCommon, EVMConstraintBuilder, StepStateTransition,
+                Transition::{Delta, To},
+            },
+            math_gadget::LtGadget,
+            memory_gadget::{
+                CommonMemoryAddressGadget, MemoryAddressGadget, MemoryCopierGasGadget,
+                MemoryExpansionGadget,
+            },
+            CachedRegion, Cell, StepRws,
+        },
+        witness::{Block, Call, Chunk, ExecStep, Transaction},
+    },
+    util::{
+        word::{WordExpr, WordLoHi},
+        Expr,
+    },
+};
+use bus_mapping::{circuit_input_builder::CopyDataType, evm::OpcodeId};
+use eth_types::{evm_types::GasCost, Field, ToScalar};
+use gadgets::util::{not, select};
+use halo2_proofs::{circuit::Value, plonk::Error};
+
+#[derive(Clone, Debug)]
+pub(crate) struct McopyGadget<F> {
+    same_context: SameContextGadget<F>,
+    /// The memory address from where we read.
+    src_memory_addr: MemoryAddressGadget<F>,
+    /// The memory address to which we write. It shares the length with
+    /// `src_memory_addr`.
+    dst_memory_addr: MemoryAddressGadget<F>,
+    /// Both the source and the destination range may expand the memory.
+    memory_expansion: MemoryExpansionGadget<F, 2, N_BYTES_MEMORY_WORD_SIZE>,
+    /// Opcode MCOPY has a dynamic gas cost:
+    /// gas_cost = static_gas + copy_gas * minimum_word_size +
+    /// memory_expansion_cost
+    memory_copier_gas: MemoryCopierGasGadget<F, { GasCost::COPY }>,
+    /// RW inverse counter from the copy table at the start of related copy
+    /// steps.
+    copy_rwc_inc: Cell<F>,
+    /// src_offset < dst_offset. Together with `dst_lt_src_end` it tells
+    /// whether the bytes are copied in decreasing address order.
+    src_lt_dst: LtGadget<F, N_BYTES_U64>,
+    /// dst_offset < src_offset + length
+    dst_lt_src_end: LtGadget<F, N_BYTES_U64>,
+}
+
+impl<F: Field> ExecutionGadget<F> for McopyGadget<F> {
+    const NAME: &'static str = "MCOPY";
+
+    const EXECUTION_STATE: ExecutionState = ExecutionState::MCOPY;
+
+    fn configure(cb: &mut EVMConstraintBuilder<F>) -> Self {
+        let opcode = cb.query_cell();
+
+        let dst_offset = cb.query_word_unchecked();
+        let src_offset = cb.query_word_unchecked();
+        let length = cb.query_memory_address();
+
+        // Pop dst_offset, src_offset, length from stack
+        cb.stack_pop(dst_offset.to_word());
+        cb.stack_pop(src_offset.to_word());
+        cb.stack_pop(WordLoHi::from_lo_unchecked(length.expr()));
+
+        let src_memory_addr = MemoryAddressGadget::construct(cb, src_offset, length.clone());
+        let dst_memory_addr = MemoryAddressGadget::construct(cb, dst_offset, length);
+
+        // The copy circuit reads each source byte right before writing the
+        // destination byte. To match the memmove semantics of geth, the bytes
+        // are copied in decreasing address order when the destination starts
+        // strictly inside the source range, and in increasing order otherwise.
+        let src_lt_dst =
+            LtGadget::construct(cb, src_memory_addr.offset(), dst_memory_addr.offset());
+        let dst_lt_src_end =
+            LtGadget::construct(cb, dst_memory_addr.offset(), src_memory_addr.address());
+        let is_reverse = src_lt_dst.expr() * dst_lt_src_end.expr();
+
+        let memory_expansion = MemoryExpansionGadget::construct(
+            cb,
+            [src_memory_addr.address(), dst_memory_addr.address()],
+        );
+        let memory_copier_gas = MemoryCopierGasGadget::construct(
+            cb,
+            dst_memory_addr.length(),
+            memory_expansion.gas_cost(),
+        );
+
+        let copy_rwc_inc = cb.query_cell();
+        cb.condition(dst_memory_addr.has_length(), |cb| {
+            // A reverse copy starts from the last byte of both ranges.
+            let src_addr = select::expr(
+                is_reverse.clone(),
+                src_memory_addr.address() - 1.expr(),
+                src_memory_addr.offset(),
+            );
+            let dst_addr = select::expr(
+                is_reverse.clone(),
+                dst_memory_addr.address() - 1.expr(),
+                dst_memory_addr.offset(),
+            );
+            cb.copy_table_lookup_with_direction(
+                WordLoHi::from_lo_unchecked(cb.curr.state.call_id.expr()),
+                CopyDataType::Memory.expr(),
+                WordLoHi::from_lo_unchecked(cb.curr.state.call_id.expr()),
+                CopyDataType::Memory.expr(),
+                src_addr,
+                src_memory_addr.address(),
+                dst_addr,
+                dst_memory_addr.length(),
+                0.expr(), // for MCOPY rlc_acc is 0
+                copy_rwc_inc.expr(),
+                is_reverse,
+            );
+        });
+        cb.condition(not::expr(dst_memory_addr.has_length()), |cb| {
+            cb.require_zero(
+                "if no bytes to copy, copy table rwc inc == 0",
+                copy_rwc_inc.expr(),
+            );
+        });
+
+        // State transition
+        let step_state_transition = StepStateTransition {
+            rw_counter: Delta(cb.rw_counter_offset()),
+            program_counter: Delta(1.expr()),
+            stack_pointer: Delta(3.expr()),
+            gas_left: Delta(
+                -(OpcodeId::MCOPY.constant_gas_cost().expr() + memory_copier_gas.gas_cost()),
+            ),
+            memory_word_size: To(memory_expansion.next_memory_word_size()),
+            ..Default::default()
+        };
+        let same_context = SameContextGadget::construct(cb, opcode, step_state_transition);
+
+        Self {
+            same_context,
+            src_memory_addr,
+            dst_memory_addr,
+            memory_expansion,
+            memory_copier_gas,
+            copy_rwc_inc,
+            src_lt_dst,
+            dst_lt_src_end,
+        }
+    }
+
+    fn assign_exec_step(
+        &self,
+        region: &mut CachedRegion<'_, '_, F>,
+        offset: usize,
+        block: &Block<F>,
+        _chunk: &Chunk<F>,
+        _tx: &Transaction,
+        _call: &Call,
+        step: &ExecStep,
+    ) -> Result<(), Error> {
+        self.same_context.assign_exec_step(region, offset, step)?;
+
+        let mut rws = StepRws::new(block, step);
+
+        let [dst_offset, src_offset, length] = [0, 1, 2].map(|_| rws.next().stack_value());
+
+        let src_address = self
+            .src_memory_addr
+            .assign(region, offset, src_offset, length)?;
+        let dst_address = self
+            .dst_memory_addr
+            .assign(region, offset, dst_offset, length)?;
+
+        // The offsets are only meaningful when there is something to copy.
+        let (src_offset, dst_offset) = if length.is_zero() {
+            (0, 0)
+        } else {
+            (src_offset.as_u64(), dst_offset.as_u64())
+        };
+        self.src_lt_dst
+            .assign(region, offset, F::from(src_offset), F::from(dst_offset))?;
+        self.dst_lt_src_end
+            .assign(region, offset, F::from(dst_offset), F::from(src_address))?;
+
+        let (_, memory_expansion_cost) = self.memory_expansion.assign(
+            region,
+            offset,
+            step.memory_word_size(),
+            [src_address, dst_address],
+        )?;
+        self.memory_copier_gas
+            .assign(region, offset, length.as_u64(), memory_expansion_cost)?;
+
+        // rw_counter always increases by `length` reads and `length` writes
+        let copy_rwc_inc = length + length;
+        self.copy_rwc_inc.assign(
+            region,
+            offset,
+            Value::known(
+                copy_rwc_inc
+                    .to_scalar()
+                    .expect("unexpected U256 -> Scalar conversion failure"),
+            ),
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{evm_circuit::test::rand_bytes, test_util::CircuitTestBuilder};
+    use eth_types::{bytecode, Word};
+    use mock::TestContext;
+
+    fn test_ok(dst_offset: Word, src_offset: usize, length: usize) {
+        let code = bytecode! {
+            PUSH32(Word::from_big_endian(&rand_bytes(32)))
+            PUSH1(0x00)
+            MSTORE
+            PUSH32(Word::from_big_endian(&rand_bytes(32)))
+            PUSH1(0x20)
+            MSTORE
+            PUSH32(length)
+            PUSH32(src_offset)
+            PUSH32(dst_offset)
+            MCOPY
+            STOP
+        };
+
+        CircuitTestBuilder::new_from_test_ctx(
+            TestContext::<2, 1>::simple_ctx_with_bytecode(code).unwrap(),
+        )
+        .run();
+    }
+
+    #[test]
+    fn mcopy_gadget_simple() {
+        test_ok(0x40.into(), 0x00, 0x20);
+    }
+
+    #[test]
+    fn mcopy_gadget_zero_length() {
+        test_ok(0x40.into(), 0x00, 0x00);
+    }
+
+    #[test]
+    fn mcopy_gadget_overflow_offset_and_zero_length() {
+        test_ok(Word::MAX, 0x00, 0x00);
+    }
+
+    #[test]
+    fn mcopy_gadget_same_offset() {
+        test_ok(0x10.into(), 0x10, 0x20);
+    }
+
+    #[test]
+    fn mcopy_gadget_overlap_dst_before_src() {
+        test_ok(0x08.into(), 0x10, 0x30);
+    }
+
+    #[test]
+    fn mcopy_gadget_overlap_dst_after_src() {
+        test_ok(0x10.into(), 0x08, 0x30);
+    }
+
+    #[test]
+    fn mcopy_gadget_expand_memory() {
+        // Both ranges are out of the current memory.
+        test_ok(0x100.into(), 0x80, 0x40);
+    }
+
+    #[test]
+    fn mcopy_gadget_long_length() {
+        test_ok(0x200.into(), 0x00, 0x150);
+    }
+}
@@ -38,6 +38,7 @@ pub(crate) struct ReturnRevertGadget<F> {
     deployed_code_rlc: Cell<F>,
 
     is_success: Cell<F>,
+    rw_counter_end_of_reversion: Cell<F>,
     restore_context: RestoreContextGadget<F>,
 
     // Used to check first byte of create init code must not be 0xef (EIP-3541).
@@ -83,6 +84,20 @@ impl<F: Field> ExecutionGadget<F> for ReturnRevertGadget<F> {
                 + not::expr(is_success.expr()) * OpcodeId::REVERT.expr(),
         );
 
+        // When the call reverts, the rollback of its reversible writes must
+        // take place right after this step, so it has to end at the rw_counter
+        // of the next step minus 1.
+        let rw_counter_end_of_reversion = cb.condition(not::expr(is_success.expr()), |cb| {
+            let rw_counter_end_of_reversion =
+                cb.call_context(None, CallContextFieldTag::RwCounterEndOfReversion);
+            cb.require_equal(
+                "rw_counter_end_of_reversion == next.rw_counter - 1",
+                rw_counter_end_of_reversion.expr(),
+                cb.next.state.rw_counter.expr() - 1.expr(),
+            );
+            rw_counter_end_of_reversion
+        });
+
         // There are 4 cases non-mutually exclusive, A to D, to handle, depending on if
         // the call is, or is not, a create, root, or successful. See the specs at
         // https://github.com/privacy-scaling-explorations/zkevm-specs/blob/master/specs/opcode/F3RETURN_FDREVERT.md
@@ -261,6 +276,7 @@ impl<F: Field> ExecutionGadget<F> for ReturnRevertGadget<F> {
             range,
             deployed_code_rlc,
             is_success,
+            rw_counter_end_of_reversion,
             init_code_first_byte,
             is_init_code_first_byte_invalid,
             copy_length,
@@ -307,6 +323,11 @@ impl<F: Field> ExecutionGadget<F> for ReturnRevertGadget<F> {
             offset,
             Value::known(F::from(call.is_success as u64)),
         )?;
+        self.rw_counter_end_of_reversion.assign(
+            region,
+            offset,
+            Value::known(F::from(call.rw_counter_end_of_reversion as u64)),
+        )?;
 
         if !call.is_root && !call.is_create() {
             for (cell, value) in [
@@ -374,11 +395,13 @@ impl<F: Field> ExecutionGadget<F> for ReturnRevertGadget<F> {
         )?;
 
         if !call.is_root {
-            let rw_counter_offset = 3 + if is_contract_deployment {
-                6 + length.as_u64()
-            } else {
-                0
-            };
+            let rw_counter_offset = 3
+                + !call.is_success as u64
+                + if is_contract_deployment {
+                    6 + length.as_u64()
+                } else {
+                    0
+                };
             self.restore_context.assign(
                 region,
                 offset,
@@ -521,6 +544,53 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_revert_nonroot_with_reversible_writes() {
+        // The transient storage writes are rolled back right after REVERT.
+        let mut callee_code = bytecode! {
+            PUSH1(0x01)
+            PUSH1(0x00)
+            TSTORE
+            PUSH1(0x02)
+            PUSH1(0x01)
+            TSTORE
+        };
+        callee_code.append(&callee_bytecode(false, 0, 10));
+        let callee = Account {
+            address: CALLEE_ADDRESS,
+            code: callee_code.into(),
+            nonce: U64::one(),
+            ..Default::default()
+        };
+        let caller = Account {
+            address: CALLER_ADDRESS,
+            code: caller_bytecode(0, 10).into(),
+            nonce: U64::one(),
+            ..Default::default()
+        };
+
+        let ctx = TestContext::<3, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(address!("0x000000000000000000000000000000000000cafe"))
+                    .balance(Word::from(10u64.pow(19)));
+                accs[1].account(&caller);
+                accs[2].account(&callee);
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .from(accs[0].address)
+                    .to(accs[1].address)
+                    .gas(100000u64.into());
+            },
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap();
+
+        CircuitTestBuilder::new_from_test_ctx(ctx).run();
+    }
+
     #[test]
     fn test_return_root_create() {
         let test_parameters = [(0, 0), (0, 10), (300, 20), (1000, 0)];
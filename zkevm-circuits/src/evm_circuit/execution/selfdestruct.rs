@@ -0,0 +1,554 @@
+use crate::{
+    evm_circuit::{
+        execution::ExecutionGadget,
+        step::ExecutionState,
+        util::{
+            common_gadget::{RestoreContextGadget, TransferToGadget},
+            constraint_builder::{
+                ConstrainBuilderCommon, EVMConstraintBuilder, ReversionInfo, StepStateTransition,
+                Transition::{Delta, Same},
+            },
+            math_gadget::{IsEqualWordGadget, IsZeroWordGadget},
+            not, select, AccountAddress, CachedRegion, Cell, StepRws,
+        },
+        witness::{Block, Call, Chunk, ExecStep, Transaction},
+    },
+    table::{AccountFieldTag, CallContextFieldTag},
+    util::{
+        word::{Word32Cell, WordExpr, WordLoHi, WordLoHiCell},
+        Expr,
+    },
+};
+use bus_mapping::evm::OpcodeId;
+use eth_types::{evm_types::GasCost, Field, OpsIdentity, ToAddress, ToScalar, U256};
+use halo2_proofs::{
+    circuit::Value,
+    plonk::{Error, Expression},
+};
+
+/// Gadget for SELFDESTRUCT. The whole balance of the current account is moved
+/// to the beneficiary. As per EIP-6780, the nonce and code hash of the current
+/// account are only set to 0 when it was created in the current transaction,
+/// in which case a balance sent to itself is burnt. Otherwise a beneficiary
+/// equal to the current account keeps its balance. Whether the account was
+/// created in the current transaction is read from the `TxCreatedAccount`
+/// records written by the creation `BeginTx` and `CREATE*` steps. All the
+/// account writes go through the reversion info, so they are undone when the
+/// current call or any of its callers reverts. Since EIP-3529 there is no gas
+/// refund for SELFDESTRUCT.
+#[derive(Clone, Debug)]
+pub(crate) struct SelfDestructGadget<F> {
+    opcode: Cell<F>,
+    beneficiary: AccountAddress<F>,
+    tx_id: Cell<F>,
+    reversion_info: ReversionInfo<F>,
+    address: WordLoHiCell<F>,
+    is_warm: Cell<F>,
+    beneficiary_code_hash: WordLoHiCell<F>,
+    beneficiary_not_exists: IsZeroWordGadget<F, WordLoHiCell<F>>,
+    is_created_in_tx: Cell<F>,
+    balance: Word32Cell<F>,
+    balance_is_zero: IsZeroWordGadget<F, Word32Cell<F>>,
+    nonce: Cell<F>,
+    code_hash: WordLoHiCell<F>,
+    is_self: IsEqualWordGadget<F, WordLoHi<Expression<F>>, WordLoHiCell<F>>,
+    transfer_to: TransferToGadget<F>,
+    restore_context: RestoreContextGadget<F>,
+}
+
+impl<F: Field> ExecutionGadget<F> for SelfDestructGadget<F> {
+    const NAME: &'static str = "SELFDESTRUCT";
+
+    const EXECUTION_STATE: ExecutionState = ExecutionState::SELFDESTRUCT;
+
+    fn configure(cb: &mut EVMConstraintBuilder<F>) -> Self {
+        let opcode = cb.query_cell();
+        cb.opcode_lookup(opcode.expr(), 1.expr());
+        // We do the responsible opcode check explicitly here because we're not using
+        // the `SameContextGadget` for `SELFDESTRUCT`.
+        cb.require_equal(
+            "Opcode should be SELFDESTRUCT",
+            opcode.expr(),
+            OpcodeId::SELFDESTRUCT.expr(),
+        );
+
+        let beneficiary = cb.query_account_address();
+        cb.stack_pop(beneficiary.to_word());
+
+        let tx_id = cb.call_context(None, CallContextFieldTag::TxId);
+        let mut reversion_info = cb.reversion_info_read(None);
+        let address = cb.call_context_read_as_word(None, CallContextFieldTag::CalleeAddress);
+
+        // Call ends with SELFDESTRUCT must be successful
+        cb.call_context_lookup_read(None, CallContextFieldTag::IsSuccess, WordLoHi::one());
+
+        let is_warm = cb.query_bool();
+        cb.account_access_list_write_unchecked(
+            tx_id.expr(),
+            beneficiary.to_word(),
+            1.expr(),
+            is_warm.expr(),
+            Some(&mut reversion_info),
+        );
+
+        // For non-existing accounts the code_hash must be 0 in the rw_table.
+        let beneficiary_code_hash = cb.query_word_unchecked();
+        cb.account_read(
+            beneficiary.to_word(),
+            AccountFieldTag::CodeHash,
+            beneficiary_code_hash.to_word(),
+        );
+        let beneficiary_not_exists = cb.is_zero_word(&beneficiary_code_hash);
+
+        let is_created_in_tx = cb.query_bool();
+        cb.tx_created_account_read(tx_id.expr(), address.to_word(), is_created_in_tx.expr());
+        let is_self = cb.is_eq_word(&beneficiary.to_word(), &address);
+
+        // The balance leaves the current account, unless the account is the
+        // beneficiary and survives the SELFDESTRUCT.
+        let balance = cb.query_word32();
+        let balance_is_zero = cb.is_zero_word(&balance);
+        let is_balance_cleared = not::expr(is_self.expr() * not::expr(is_created_in_tx.expr()));
+        cb.condition(is_balance_cleared.clone(), |cb| {
+            cb.account_write(
+                address.to_word(),
+                AccountFieldTag::Balance,
+                WordLoHi::zero(),
+                balance.to_word(),
+                Some(&mut reversion_info),
+            );
+        });
+
+        // Only an account created in the current transaction is deleted.
+        let nonce = cb.query_cell();
+        let code_hash = cb.query_word_unchecked();
+        cb.condition(is_created_in_tx.expr(), |cb| {
+            for (field_tag, value_prev) in [
+                (
+                    AccountFieldTag::Nonce,
+                    WordLoHi::from_lo_unchecked(nonce.expr()),
+                ),
+                (AccountFieldTag::CodeHash, code_hash.to_word()),
+            ] {
+                cb.account_write(
+                    address.to_word(),
+                    field_tag,
+                    WordLoHi::zero(),
+                    value_prev,
+                    Some(&mut reversion_info),
+                );
+            }
+        });
+
+        // The balance is burnt when the beneficiary is the deleted current account.
+        let transfer_to = cb.condition(not::expr(is_self.expr()), |cb| {
+            TransferToGadget::construct(
+                cb,
+                beneficiary.to_word(),
+                not::expr(beneficiary_not_exists.expr()),
+                0.expr(),
+                balance.clone(),
+                Some(&mut reversion_info),
+            )
+        });
+
+        // Access list write and the account writes of the current account, plus the
+        // beneficiary writes of the transfer.
+        let reversible_write_counter_delta = 1.expr()
+            + is_balance_cleared
+            + 2.expr() * is_created_in_tx.expr()
+            + not::expr(is_self.expr()) * transfer_to.rw_delta();
+
+        let gas_cost = GasCost::SELFDESTRUCT.expr()
+            + select::expr(
+                is_warm.expr(),
+                0.expr(),
+                GasCost::COLD_ACCOUNT_ACCESS.expr(),
+            )
+            + beneficiary_not_exists.expr()
+                * not::expr(balance_is_zero.expr())
+                * GasCost::NEW_ACCOUNT.expr();
+
+        let is_to_end_tx = cb.next.execution_state_selector([ExecutionState::EndTx]);
+        cb.require_equal(
+            "Go to EndTx only when is_root",
+            cb.curr.state.is_root.expr(),
+            is_to_end_tx,
+        );
+
+        // When it's a root call
+        cb.condition(cb.curr.state.is_root.expr(), |cb| {
+            cb.require_step_state_transition(StepStateTransition {
+                call_id: Same,
+                rw_counter: Delta(cb.rw_counter_offset()),
+                gas_left: Delta(-gas_cost.clone()),
+                reversible_write_counter: Delta(reversible_write_counter_delta.clone()),
+                ..StepStateTransition::any()
+            });
+        });
+
+        // When it's an internal call
+        let restore_context = cb.condition(not::expr(cb.curr.state.is_root.expr()), |cb| {
+            RestoreContextGadget::construct2(
+                cb,
+                true.expr(),
+                gas_cost,
+                0.expr(),
+                0.expr(),
+                0.expr(),
+                0.expr(),
+                reversible_write_counter_delta,
+            )
+        });
+
+        Self {
+            opcode,
+            beneficiary,
+            tx_id,
+            reversion_info,
+            address,
+            is_warm,
+            beneficiary_code_hash,
+            beneficiary_not_exists,
+            is_created_in_tx,
+            balance,
+            balance_is_zero,
+            nonce,
+            code_hash,
+            is_self,
+            transfer_to,
+            restore_context,
+        }
+    }
+
+    fn assign_exec_step(
+        &self,
+        region: &mut CachedRegion<'_, '_, F>,
+        offset: usize,
+        block: &Block<F>,
+        _chunk: &Chunk<F>,
+        tx: &Transaction,
+        call: &Call,
+        step: &ExecStep,
+    ) -> Result<(), Error> {
+        let opcode = step.opcode().unwrap();
+        self.opcode
+            .assign(region, offset, Value::known(F::from(opcode.as_u64())))?;
+
+        let mut rws = StepRws::new(block, step);
+
+        let beneficiary = rws.next().stack_value();
+        self.beneficiary
+            .assign_h160(region, offset, beneficiary.to_address())?;
+
+        self.tx_id
+            .assign(region, offset, Value::known(F::from(tx.id)))?;
+        self.reversion_info.assign(
+            region,
+            offset,
+            call.rw_counter_end_of_reversion,
+            call.is_persistent,
+        )?;
+        rws.offset_add(3);
+
+        let address = rws.next().call_context_value();
+        self.address.assign_u256(region, offset, address)?;
+        // IsSuccess
+        rws.offset_add(1);
+
+        let (_, is_warm) = rws.next().tx_access_list_value_pair();
+        self.is_warm
+            .assign(region, offset, Value::known(F::from(is_warm as u64)))?;
+
+        let beneficiary_code_hash = rws.next().account_codehash_pair().0;
+        self.beneficiary_code_hash
+            .assign_u256(region, offset, beneficiary_code_hash)?;
+        self.beneficiary_not_exists.assign_value(
+            region,
+            offset,
+            Value::known(WordLoHi::from(beneficiary_code_hash)),
+        )?;
+
+        let is_created_in_tx = rws.next().tx_created_account_value();
+        self.is_created_in_tx.assign(
+            region,
+            offset,
+            Value::known(F::from(is_created_in_tx as u64)),
+        )?;
+        let is_self = self
+            .is_self
+            .assign_u256(region, offset, beneficiary, address)?;
+
+        let balance = if is_self == F::ZERO || is_created_in_tx {
+            rws.next().account_balance_pair().1
+        } else {
+            U256::zero()
+        };
+        self.balance.assign_u256(region, offset, balance)?;
+        self.balance_is_zero
+            .assign_value(region, offset, Value::known(WordLoHi::from(balance)))?;
+
+        let (nonce, code_hash) = if is_created_in_tx {
+            (
+                rws.next().account_nonce_pair().1,
+                rws.next().account_codehash_pair().1,
+            )
+        } else {
+            (U256::zero(), U256::zero())
+        };
+        self.nonce.assign(
+            region,
+            offset,
+            Value::known(
+                nonce
+                    .to_scalar()
+                    .expect("unexpected U256 -> Scalar conversion failure"),
+            ),
+        )?;
+        self.code_hash.assign_u256(region, offset, code_hash)?;
+
+        if is_self == F::ZERO {
+            self.transfer_to.assign(
+                region,
+                offset,
+                &mut rws,
+                !beneficiary_code_hash.is_zero(),
+                balance,
+                false,
+            )?;
+        }
+
+        if !call.is_root {
+            self.restore_context
+                .assign(region, offset, block, call, step, rws.offset())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_util::CircuitTestBuilder;
+    use eth_types::{address, bytecode, Address, Bytecode, ToWord, Word};
+    use lazy_static::lazy_static;
+    use mock::TestContext;
+
+    lazy_static! {
+        static ref CONTRACT: Address = address!("0x0000000000000000000000000000000000000010");
+        static ref CALLER: Address = address!("0x0000000000000000000000000000000000000020");
+    }
+
+    fn selfdestruct_code(beneficiary: Address) -> Bytecode {
+        bytecode! {
+            PUSH20(beneficiary.to_word())
+            SELFDESTRUCT
+        }
+    }
+
+    fn test_root_ok(beneficiary: Address, balance: Word) {
+        let ctx = TestContext::<2, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(address!("0x0000000000000000000000000000000000000123"))
+                    .balance(Word::from(1u64 << 30));
+                accs[1]
+                    .address(*CONTRACT)
+                    .balance(balance)
+                    .code(selfdestruct_code(beneficiary));
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .from(accs[0].address)
+                    .to(accs[1].address)
+                    .gas(Word::from(100000));
+            },
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap();
+
+        CircuitTestBuilder::new_from_test_ctx(ctx).run();
+    }
+
+    fn test_internal_ok(beneficiary: Address, balance: Word, is_reverted: bool) {
+        let mut caller_code = bytecode! {
+            PUSH1(0)
+            PUSH1(0)
+            PUSH1(0)
+            PUSH1(0)
+            PUSH1(0)
+            PUSH20(CONTRACT.to_word())
+            GAS
+            CALL
+        };
+        if is_reverted {
+            caller_code.append(&bytecode! {
+                PUSH1(0)
+                PUSH1(0)
+                REVERT
+            });
+        } else {
+            caller_code.append(&bytecode! {
+                STOP
+            });
+        }
+
+        let ctx = TestContext::<3, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(address!("0x0000000000000000000000000000000000000123"))
+                    .balance(Word::from(1u64 << 30));
+                accs[1]
+                    .address(*CALLER)
+                    .balance(Word::from(1u64 << 20))
+                    .code(caller_code);
+                accs[2]
+                    .address(*CONTRACT)
+                    .balance(balance)
+                    .code(selfdestruct_code(beneficiary));
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .from(accs[0].address)
+                    .to(accs[1].address)
+                    .gas(Word::from(100000));
+            },
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap();
+
+        CircuitTestBuilder::new_from_test_ctx(ctx).run();
+    }
+
+    fn test_created_in_tx_ok(initialization_code: Bytecode) {
+        let initialization_bytes = initialization_code.code();
+        let mut caller_code = Bytecode::default();
+        // Store the initialization code at the end of the first memory word.
+        caller_code.push(32, Word::from_big_endian(&initialization_bytes));
+        caller_code.append(&bytecode! {
+            PUSH1(0)
+            MSTORE
+            PUSH1(initialization_bytes.len()) // size
+            PUSH1(32 - initialization_bytes.len()) // offset
+            PUSH2(1000) // value
+            CREATE
+            STOP
+        });
+
+        let ctx = TestContext::<2, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(address!("0x0000000000000000000000000000000000000123"))
+                    .balance(Word::from(1u64 << 30));
+                accs[1]
+                    .address(*CALLER)
+                    .balance(Word::from(1u64 << 20))
+                    .code(caller_code);
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .from(accs[0].address)
+                    .to(accs[1].address)
+                    .gas(Word::from(200000));
+            },
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap();
+
+        CircuitTestBuilder::new_from_test_ctx(ctx).run();
+    }
+
+    #[test]
+    fn selfdestruct_gadget_root() {
+        let new_account = address!("0x00000000000000000000000000000000000cafe1");
+        test_root_ok(new_account, Word::from(1000u64));
+        test_root_ok(new_account, Word::zero());
+        test_root_ok(*CONTRACT, Word::from(1000u64));
+    }
+
+    #[test]
+    fn selfdestruct_gadget_existing_beneficiary() {
+        test_root_ok(
+            address!("0x0000000000000000000000000000000000000123"),
+            Word::from(1000u64),
+        );
+        test_internal_ok(*CALLER, Word::from(1000u64), false);
+    }
+
+    #[test]
+    fn selfdestruct_gadget_internal() {
+        let new_account = address!("0x00000000000000000000000000000000000cafe1");
+        test_internal_ok(new_account, Word::from(1000u64), false);
+        test_internal_ok(new_account, Word::zero(), false);
+        test_internal_ok(*CONTRACT, Word::from(1000u64), false);
+    }
+
+    #[test]
+    fn selfdestruct_gadget_reverted() {
+        let new_account = address!("0x00000000000000000000000000000000000cafe1");
+        test_internal_ok(new_account, Word::from(1000u64), true);
+        test_internal_ok(*CONTRACT, Word::from(1000u64), true);
+    }
+
+    #[test]
+    fn selfdestruct_gadget_created_in_tx() {
+        let new_account = address!("0x00000000000000000000000000000000000cafe1");
+        test_created_in_tx_ok(selfdestruct_code(new_account));
+        test_created_in_tx_ok(bytecode! {
+            ADDRESS
+            SELFDESTRUCT
+        });
+    }
+
+    #[test]
+    fn selfdestruct_gadget_call_after_selfdestruct() {
+        // CONTRACT was not created in this transaction, so it keeps its code and
+        // its balance, and runs SELFDESTRUCT again on the second call.
+        let call_contract = bytecode! {
+            PUSH1(0)
+            PUSH1(0)
+            PUSH1(0)
+            PUSH1(0)
+            PUSH1(0)
+            PUSH20(CONTRACT.to_word())
+            GAS
+            CALL
+            POP
+        };
+        let mut caller_code = call_contract.clone();
+        caller_code.append(&call_contract);
+        caller_code.append(&bytecode! {
+            STOP
+        });
+
+        let ctx = TestContext::<3, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(address!("0x0000000000000000000000000000000000000123"))
+                    .balance(Word::from(1u64 << 30));
+                accs[1]
+                    .address(*CALLER)
+                    .balance(Word::from(1u64 << 20))
+                    .code(caller_code);
+                accs[2]
+                    .address(*CONTRACT)
+                    .balance(Word::from(1000u64))
+                    .code(selfdestruct_code(*CONTRACT));
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .from(accs[0].address)
+                    .to(accs[1].address)
+                    .gas(Word::from(100000));
+            },
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap();
+
+        CircuitTestBuilder::new_from_test_ctx(ctx).run();
+    }
+}
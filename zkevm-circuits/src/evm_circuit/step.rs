@@ -24,7 +24,7 @@ use bus_mapping::{
     evm::OpcodeId,
     precompile::PrecompileCalls,
 };
-use eth_types::{evm_unimplemented, Field, ToWord};
+use eth_types::{Field, ToWord};
 use halo2_proofs::{
     circuit::Value,
     plonk::{Advice, Column, ConstraintSystem, Error, Expression},
@@ -121,6 +121,7 @@ pub enum ExecutionState {
     JUMPDEST,
     TLOAD,
     TSTORE,
+    MCOPY,
     /// PUSH0, PUSH1, PUSH2, ..., PUSH32
     PUSH,
     /// DUP1, DUP2, ..., DUP16
@@ -258,13 +259,6 @@ impl From<&ExecStep> for ExecutionState {
                     return ExecutionState::LOG;
                 }
 
-                macro_rules! dummy {
-                    ($name:expr) => {{
-                        evm_unimplemented!("{:?} is implemented with DummyGadget", $name);
-                        $name
-                    }};
-                }
-
                 match op {
                     OpcodeId::ADD | OpcodeId::SUB => ExecutionState::ADD_SUB,
                     OpcodeId::ADDMOD => ExecutionState::ADDMOD,
@@ -313,6 +307,7 @@ impl From<&ExecStep> for ExecutionState {
                     OpcodeId::SSTORE => ExecutionState::SSTORE,
                     OpcodeId::TLOAD => ExecutionState::TLOAD,
                     OpcodeId::TSTORE => ExecutionState::TSTORE,
+                    OpcodeId::MCOPY => ExecutionState::MCOPY,
                     OpcodeId::CALLDATASIZE => ExecutionState::CALLDATASIZE,
                     OpcodeId::CALLDATACOPY => ExecutionState::CALLDATACOPY,
                     OpcodeId::CHAINID => ExecutionState::CHAINID,
@@ -331,8 +326,7 @@ impl From<&ExecStep> for ExecutionState {
                     OpcodeId::RETURNDATACOPY => ExecutionState::RETURNDATACOPY,
                     OpcodeId::CREATE => ExecutionState::CREATE,
                     OpcodeId::CREATE2 => ExecutionState::CREATE2,
-                    // dummy ops
-                    OpcodeId::SELFDESTRUCT => dummy!(ExecutionState::SELFDESTRUCT),
+                    OpcodeId::SELFDESTRUCT => ExecutionState::SELFDESTRUCT,
                     _ => unimplemented!("unimplemented opcode {:?}", op),
                 }
             }
@@ -512,6 +506,7 @@ impl ExecutionState {
             Self::JUMPDEST => vec![OpcodeId::JUMPDEST],
             Self::TLOAD => vec![OpcodeId::TLOAD],
             Self::TSTORE => vec![OpcodeId::TSTORE],
+            Self::MCOPY => vec![OpcodeId::MCOPY],
             Self::PUSH => vec![
                 OpcodeId::PUSH0,
                 OpcodeId::PUSH1,
@@ -327,13 +327,17 @@ pub(crate) enum Lookup<F> {
         dst_id: WordLoHi<Expression<F>>,
         /// The destination tag for the copy event.
         dst_tag: Expression<F>,
-        /// The source address where bytes are copied from.
+        /// The source address where bytes are copied from. For a reverse copy
+        /// event this is the address of the first copied byte, i.e. the last
+        /// byte of the source range.
         src_addr: Expression<F>,
         /// The source address where all source-side bytes have been copied.
         /// This does not necessarily mean there no more bytes to be copied, but
         /// any bytes following this address will indicating padding.
         src_addr_end: Expression<F>,
-        /// The destination address at which bytes are copied.
+        /// The destination address at which bytes are copied. For a reverse
+        /// copy event this is the address of the last byte of the destination
+        /// range.
         dst_addr: Expression<F>,
         /// The number of bytes to be copied in this copy event.
         length: Expression<F>,
@@ -344,6 +348,8 @@ pub(crate) enum Lookup<F> {
         /// The RW counter that is incremented by the time all bytes have been
         /// copied specific to this copy event.
         rwc_inc: Expression<F>,
+        /// Whether the bytes are copied in decreasing address order.
+        is_reverse: Expression<F>,
     },
     /// Lookup to keccak table.
     KeccakTable {
@@ -472,6 +478,7 @@ impl<F: Field> Lookup<F> {
                 rlc_acc,
                 rw_counter,
                 rwc_inc,
+                is_reverse,
             } => vec![
                 is_first.clone(),
                 src_id.lo(),
@@ -487,6 +494,7 @@ impl<F: Field> Lookup<F> {
                 rlc_acc.clone(),
                 rw_counter.clone(),
                 rwc_inc.clone(),
+                is_reverse.clone(),
             ],
             Self::KeccakTable {
                 input_rlc,
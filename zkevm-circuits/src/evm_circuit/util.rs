@@ -469,6 +469,10 @@ impl<'a> StepRws<'a> {
     pub(crate) fn offset_add(&mut self, inc: usize) {
         self.offset += inc
     }
+    /// Return the current step rw operation offset.
+    pub(crate) fn offset(&self) -> usize {
+        self.offset
+    }
     /// Return the next rw operation from the step.
     pub(crate) fn next(&mut self) -> Rw {
         let rw = self.rws[self.step.rw_index(self.offset)];
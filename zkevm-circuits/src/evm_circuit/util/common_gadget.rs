@@ -135,6 +135,7 @@ impl<F: Field> RestoreContextGadget<F> {
     pub(crate) fn construct2(
         cb: &mut EVMConstraintBuilder<F>,
         is_success: Expression<F>,
+        // Gas cost of the step, deducted before the remaining gas is returned to the caller.
         gas_cost: Expression<F>,
         // Expression for the number of rw lookups that occur after this gadget is constructed.
         subsequent_rw_lookups: Expression<F>,
@@ -205,7 +206,7 @@ impl<F: Field> RestoreContextGadget<F> {
         } else if cb.execution_state().is_precompiled() {
             cb.curr.state.gas_left.expr() - gas_cost.expr()
         } else {
-            cb.curr.state.gas_left.expr() - memory_expansion_cost - code_deposit_cost
+            cb.curr.state.gas_left.expr() - gas_cost - memory_expansion_cost - code_deposit_cost
         };
 
         let gas_left = caller_gas_left.expr() + gas_refund;
@@ -997,6 +998,8 @@ impl<F: Field> CommonErrorGadget<F> {
                 rw_counter: Delta(
                     updated_rw_counter_delta + cb.curr.state.reversible_write_counter.expr(),
                 ),
+                // All remaining gas is consumed when the call halts in exception
+                gas_left: To(0.expr()),
                 ..StepStateTransition::any()
             });
         });
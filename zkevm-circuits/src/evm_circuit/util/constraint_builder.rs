@@ -1068,6 +1068,54 @@ impl<'a, F: Field> EVMConstraintBuilder<'a, F> {
         );
     }
 
+    // Tx Created Account
+
+    pub(crate) fn tx_created_account_read(
+        &mut self,
+        tx_id: Expression<F>,
+        account_address: WordLoHi<Expression<F>>,
+        value: Expression<F>,
+    ) {
+        self.rw_lookup(
+            "TxCreatedAccount read",
+            false.expr(),
+            Target::TxCreatedAccount,
+            RwValues::new(
+                tx_id,
+                account_address.compress(),
+                0.expr(),
+                WordLoHi::zero(),
+                WordLoHi::from_lo_unchecked(value.clone()),
+                WordLoHi::from_lo_unchecked(value),
+                WordLoHi::zero(),
+            ),
+        );
+    }
+
+    pub(crate) fn tx_created_account_write(
+        &mut self,
+        tx_id: Expression<F>,
+        account_address: WordLoHi<Expression<F>>,
+        value: Expression<F>,
+        value_prev: Expression<F>,
+        reversion_info: Option<&mut ReversionInfo<F>>,
+    ) {
+        self.reversible_write(
+            "TxCreatedAccount write",
+            Target::TxCreatedAccount,
+            RwValues::new(
+                tx_id,
+                account_address.compress(),
+                0.expr(),
+                WordLoHi::zero(),
+                WordLoHi::from_lo_unchecked(value),
+                WordLoHi::from_lo_unchecked(value_prev),
+                WordLoHi::zero(),
+            ),
+            reversion_info,
+        );
+    }
+
     // Tx Refund
 
     pub(crate) fn tx_refund_read(&mut self, tx_id: Expression<F>, value: WordLoHi<Expression<F>>) {
@@ -1670,6 +1718,39 @@ impl<'a, F: Field> EVMConstraintBuilder<'a, F> {
         length: Expression<F>,
         rlc_acc: Expression<F>,
         rwc_inc: Expression<F>,
+    ) {
+        self.copy_table_lookup_with_direction(
+            src_id,
+            src_tag,
+            dst_id,
+            dst_tag,
+            src_addr,
+            src_addr_end,
+            dst_addr,
+            length,
+            rlc_acc,
+            rwc_inc,
+            0.expr(),
+        );
+    }
+
+    /// Same as `copy_table_lookup`, for a copy event that may copy the bytes in
+    /// decreasing address order. When `is_reverse` is set, `src_addr` and
+    /// `dst_addr` are the addresses of the last byte of each range.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn copy_table_lookup_with_direction(
+        &mut self,
+        src_id: WordLoHi<Expression<F>>,
+        src_tag: Expression<F>,
+        dst_id: WordLoHi<Expression<F>>,
+        dst_tag: Expression<F>,
+        src_addr: Expression<F>,
+        src_addr_end: Expression<F>,
+        dst_addr: Expression<F>,
+        length: Expression<F>,
+        rlc_acc: Expression<F>,
+        rwc_inc: Expression<F>,
+        is_reverse: Expression<F>,
     ) {
         self.add_lookup(
             "copy lookup",
@@ -1686,6 +1767,7 @@ impl<'a, F: Field> EVMConstraintBuilder<'a, F> {
                 rlc_acc,
                 rw_counter: self.curr.state.rw_counter.expr() + self.rw_counter_offset(),
                 rwc_inc: rwc_inc.clone(),
+                is_reverse,
             },
         );
         self.rw_counter_offset = self.rw_counter_offset.clone() + self.condition_expr() * rwc_inc;
@@ -26,8 +26,8 @@ use crate::{
     evm_circuit::util::constraint_builder::{BaseConstraintBuilder, ConstrainBuilderCommon},
     keccak_circuit::keccak_packed_multi::{
         decode, get_num_bits_per_absorb_lookup, get_num_bits_per_base_chi_lookup,
-        get_num_bits_per_rho_pi_lookup, get_num_bits_per_theta_c_lookup, get_num_rows_per_round,
-        split, split_uniform, transform, transform_to, Part,
+        get_num_bits_per_rho_pi_lookup, get_num_bits_per_theta_c_lookup, get_num_keccak_f,
+        get_num_rows_per_round, split, split_uniform, transform, transform_to, Part,
     },
     table::{KeccakTable, LookupTable},
     util::{
@@ -67,9 +67,30 @@ pub struct KeccakCircuitConfig<F> {
     normalize_6: [TableColumn; 2],
     chi_base_table: [TableColumn; 2],
     pack_table: [TableColumn; 2],
+    #[allow(dead_code, reason = "the cell handles are only read by the tests")]
+    round_cells: KeccakRoundCells<F>,
     _marker: PhantomData<F>,
 }
 
+/// Handles of some cells of the round layout, used to locate their witness
+/// values in a round
+#[derive(Clone, Debug)]
+#[allow(dead_code, reason = "the cell handles are only read by the tests")]
+pub(crate) struct KeccakRoundCells<F> {
+    /// The 25 state words, in row-major order of `s[i][j]`
+    state: Vec<Cell<F>>,
+    /// The input word absorbed in the round
+    absorb_data: Cell<F>,
+    /// The padding flags of the bytes of the absorbed word
+    is_paddings: Vec<Cell<F>>,
+    /// The first rho/pi output part, normalized by the `rho/pi` lookup
+    rho_pi: Cell<F>,
+    /// The first chi output part, checked by the `chi base` lookup
+    chi: Cell<F>,
+    /// The state word converted to bytes for the squeeze
+    squeeze_from: Cell<F>,
+}
+
 /// Circuit configuration arguments
 pub struct KeccakCircuitConfigArgs<F: Field> {
     /// KeccakTable
@@ -135,11 +156,13 @@ impl<F: Field> SubCircuitConfig<F> for KeccakCircuitConfig<F> {
         // State data
         let mut s = vec![vec![0u64.expr(); 5]; 5];
         let mut s_next = vec![vec![0u64.expr(); 5]; 5];
+        let mut state_cells = Vec::new();
         for i in 0..5 {
             for j in 0..5 {
                 let cell = cell_manager.query_cell(meta, DEFAULT_CELL_TYPE);
                 s[i][j] = cell.expr();
                 s_next[i][j] = cell.at_offset(meta, get_num_rows_per_round() as i32).expr();
+                state_cells.push(cell);
             }
         }
         // Absorb data
@@ -872,6 +895,14 @@ impl<F: Field> SubCircuitConfig<F> for KeccakCircuitConfig<F> {
             normalize_6,
             chi_base_table,
             pack_table,
+            round_cells: KeccakRoundCells {
+                state: state_cells,
+                absorb_data,
+                is_paddings,
+                rho_pi: rho_pi_chi_cells[1][0][0][0].clone(),
+                chi: rho_pi_chi_cells[2][0][0][0].clone(),
+                squeeze_from,
+            },
             _marker: PhantomData,
         }
     }
@@ -986,7 +1017,12 @@ impl<F: Field> KeccakCircuitConfig<F> {
         region.name_column(|| "KECCAK_q_round", self.q_round);
         region.name_column(|| "KECCAK_q_absorb", self.q_absorb);
         region.name_column(|| "KECCAK_q_round_last", self.q_round_last);
+        region.name_column(|| "KECCAK_q_padding", self.q_padding);
         region.name_column(|| "KECCAK_q_padding_last", self.q_padding_last);
+        region.name_column(|| "KECCAK_round_cst", self.round_cst);
+        for (idx, column) in self.cell_manager.columns().iter().enumerate() {
+            region.name_column(|| format!("KECCAK_cell_{}", idx), column.advice);
+        }
     }
 }
 
@@ -1017,13 +1053,8 @@ impl<F: Field> SubCircuit<F> for KeccakCircuit<F> {
 
     /// Return the minimum number of rows required to prove the block
     fn min_num_rows_block(block: &witness::Block<F>, chunk: &Chunk<F>) -> (usize, usize) {
-        let rows_perchunk = (NUM_ROUNDS + 1) * get_num_rows_per_round();
         (
-            block
-                .keccak_inputs
-                .iter()
-                .map(|bytes| (bytes.len() as f64 / 136.0).ceil() as usize * rows_perchunk)
-                .sum(),
+            Self::min_num_rows(&block.keccak_inputs),
             chunk.fixed_param.max_keccak_rows,
         )
     }
@@ -1051,6 +1082,35 @@ impl<F: Field> KeccakCircuit<F> {
         }
     }
 
+    /// Return the minimum number of rows required to hash `inputs`, excluding
+    /// the unusable rows.
+    pub fn min_num_rows(inputs: &[Vec<u8>]) -> usize {
+        let rows_per_keccak_f = (NUM_ROUNDS + 1) * get_num_rows_per_round();
+        // The dummy first round that absorbs the initial state
+        get_num_rows_per_round()
+            + inputs
+                .iter()
+                .map(|bytes| get_num_keccak_f(bytes.len()) * rows_per_keccak_f)
+                .sum::<usize>()
+    }
+
+    /// Return the degree of the constraint system of the circuit. Together with
+    /// `min_num_rows` and `unusable_rows` this determines the smallest usable
+    /// k.
+    pub fn degree() -> usize {
+        let mut meta = ConstraintSystem::<F>::default();
+        let keccak_table = KeccakTable::construct(&mut meta);
+        let challenges = Challenges::construct(&mut meta).exprs(&mut meta);
+        KeccakCircuitConfig::new(
+            &mut meta,
+            KeccakCircuitConfigArgs {
+                keccak_table,
+                challenges,
+            },
+        );
+        meta.degree()
+    }
+
     /// The number of keccak_f's that can be done in this circuit
     pub fn capacity(&self) -> Option<usize> {
         if self.num_rows > 0 {
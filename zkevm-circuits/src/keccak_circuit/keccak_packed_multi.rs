@@ -10,6 +10,8 @@ use halo2_proofs::{
     plonk::{Error, Expression},
 };
 use log::debug;
+#[cfg(feature = "par-witness")]
+use rayon::prelude::*;
 use std::{env::var, vec};
 
 pub(crate) fn get_num_rows_per_round() -> usize {
@@ -27,6 +29,13 @@ pub(crate) fn keccak_unusable_rows() -> usize {
     UNUSABLE_ROWS_BY_KECCAK_ROWS[get_num_rows_per_round() - NUM_BYTES_PER_WORD - 1]
 }
 
+/// Returns the number of keccak_f's needed to hash `num_bytes` bytes. Padding
+/// always adds at least one byte, so a length that is a multiple of the rate
+/// still needs an extra block.
+pub(crate) fn get_num_keccak_f(num_bytes: usize) -> usize {
+    num_bytes / RATE + 1
+}
+
 pub(crate) fn get_num_bits_per_absorb_lookup() -> usize {
     get_num_bits_per_lookup(ABSORB_LOOKUP_RANGE)
 }
@@ -893,9 +902,25 @@ pub(crate) fn multi_keccak<F: Field>(
         });
     }
     // Actual keccaks
+    #[cfg(not(feature = "par-witness"))]
     for bytes in bytes {
         keccak(&mut rows, bytes, challenges);
     }
+    // The rows of each input only depend on that input, so they can be computed
+    // independently and concatenated in order.
+    #[cfg(feature = "par-witness")]
+    rows.extend(
+        bytes
+            .par_iter()
+            .map(|bytes| {
+                let mut rows = Vec::new();
+                keccak(&mut rows, bytes, challenges);
+                rows
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flatten(),
+    );
     if let Some(capacity) = capacity {
         let padding_rows = {
             let mut rows = Vec::new();
@@ -5,13 +5,18 @@ use crate::{
 };
 use bus_mapping::state_db::EMPTY_CODE_HASH_LE;
 use eth_types::{Field, H256, U256};
+use ethers_core::utils::keccak256;
 use halo2_proofs::{
-    dev::{CellValue, MockProver},
+    arithmetic::Field as Halo2Field,
+    circuit::SimpleFloorPlanner,
+    dev::{CellValue, MockProver, VerifyFailure},
     halo2curves::bn256::Fr,
     plonk::{Assignment, Circuit},
 };
 use itertools::izip;
 use log::error;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use std::iter::zip;
 
 use super::util::{target_part_sizes, target_part_sizes_rot, WordParts};
@@ -127,6 +132,286 @@ fn packed_multi_keccak_simple() {
     verify::<Fr>(k, inputs, digests, true);
 }
 
+/// Keccak circuit whose witness is modified by `tamper` before it is
+/// assigned, for negative tests.
+#[derive(Clone)]
+struct TamperedKeccakCircuit {
+    circuit: KeccakCircuit<Fr>,
+    tamper: fn(&KeccakCircuitConfig<Fr>, &mut [KeccakRow<Fr>]),
+}
+
+impl Circuit<Fr> for TamperedKeccakCircuit {
+    type Config = (KeccakCircuitConfig<Fr>, Challenges);
+    type FloorPlanner = SimpleFloorPlanner;
+    type Params = ();
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            circuit: KeccakCircuit::default(),
+            tamper: self.tamper,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+        KeccakCircuit::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        (config, challenges): Self::Config,
+        mut layouter: impl Layouter<Fr>,
+    ) -> Result<(), Error> {
+        let challenges = challenges.values(&mut layouter);
+        config.load_aux_tables(&mut layouter)?;
+        let mut witness = self.circuit.generate_witness(challenges);
+        (self.tamper)(&config, &mut witness);
+        config.assign(&mut layouter, witness.as_slice())
+    }
+}
+
+/// Runs the circuit on `inputs` with the witness modified by `tamper` and
+/// returns the verification result.
+fn verify_tampered(
+    k: u32,
+    inputs: Vec<Vec<u8>>,
+    tamper: fn(&KeccakCircuitConfig<Fr>, &mut [KeccakRow<Fr>]),
+) -> Result<(), Vec<VerifyFailure>> {
+    // Sanity check that the untampered witness is valid.
+    let circuit = KeccakCircuit::new(2usize.pow(k), inputs.clone());
+    let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+
+    let circuit = TamperedKeccakCircuit {
+        circuit: KeccakCircuit::new(2usize.pow(k), inputs),
+        tamper,
+    };
+    let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+    prover.verify()
+}
+
+fn assert_error_matches(result: Result<(), Vec<VerifyFailure>>, name: &str) {
+    let errors = result.expect_err("result is not an error");
+    errors
+        .iter()
+        .find(|err| match err {
+            VerifyFailure::ConstraintNotSatisfied { constraint, .. } => {
+                // fields of halo2_proofs::dev::metadata::Constraint aren't public, so we have
+                // to match off of its format string.
+                format!("{}", constraint).contains(name)
+            }
+            VerifyFailure::Lookup {
+                name: lookup_name, ..
+            } => lookup_name == name,
+            _ => false,
+        })
+        .unwrap_or_else(|| {
+            panic!(
+                "there is no constraints contain {}; err {:#?}",
+                name, errors
+            )
+        });
+}
+
+/// Returns the offset of the first row of `round` of the first hash, which
+/// follows the dummy first round.
+fn round_offset(round: usize) -> usize {
+    (1 + round) * get_num_rows_per_round()
+}
+
+/// Sets the witness value of `cell` in the round starting at `offset`.
+fn set_cell(witness: &mut [KeccakRow<Fr>], offset: usize, cell: &Cell<Fr>, value: Fr) {
+    let cell_values = &mut witness[offset + cell.get_rotation()].cell_values;
+    if cell_values.len() <= cell.get_column_idx() {
+        cell_values.resize(cell.get_column_idx() + 1, Fr::ZERO);
+    }
+    cell_values[cell.get_column_idx()] = value;
+}
+
+#[test]
+fn tampered_state_in_round() {
+    let result = verify_tampered(14, vec![vec![1, 2, 3]], |config, witness| {
+        set_cell(
+            witness,
+            round_offset(4),
+            &config.round_cells.state[0],
+            Fr::from(0xdead),
+        )
+    });
+    assert_error_matches(result, "next row check");
+}
+
+#[test]
+fn tampered_state_in_last_round() {
+    // Changing the state of the last round of the final block changes the
+    // squeezed digest, which has to be caught by the squeeze constraints.
+    let result = verify_tampered(14, vec![vec![1, 2, 3]], |config, witness| {
+        set_cell(
+            witness,
+            round_offset(NUM_ROUNDS),
+            &config.round_cells.state[0],
+            Fr::from(0xdead),
+        )
+    });
+    assert_error_matches(result, "squeeze verify packed");
+}
+
+#[test]
+fn tampered_absorb_data() {
+    // The first round of a hash absorbs the first input word into the state.
+    let result = verify_tampered(14, vec![vec![1, 2, 3]], |config, witness| {
+        set_cell(
+            witness,
+            round_offset(0),
+            &config.round_cells.absorb_data,
+            Fr::from(0xdead),
+        )
+    });
+    assert_error_matches(result, "absorb result copy");
+}
+
+#[test]
+fn tampered_padding_flag() {
+    // Marking the third input byte as padding has to be rejected as it is
+    // neither the 0x01 padding start byte nor zero.
+    let result = verify_tampered(14, vec![vec![1, 2, 3]], |config, witness| {
+        set_cell(
+            witness,
+            round_offset(0),
+            &config.round_cells.is_paddings[2],
+            Fr::ONE,
+        )
+    });
+    assert_error_matches(result, "padding start/intermediate byte");
+}
+
+#[test]
+fn tampered_rho_pi_normalize() {
+    let result = verify_tampered(14, vec![vec![1, 2, 3]], |config, witness| {
+        set_cell(
+            witness,
+            round_offset(4),
+            &config.round_cells.rho_pi,
+            Fr::from(0xdead),
+        )
+    });
+    assert_error_matches(result, "rho/pi");
+}
+
+#[test]
+fn tampered_chi_normalize() {
+    let result = verify_tampered(14, vec![vec![1, 2, 3]], |config, witness| {
+        set_cell(
+            witness,
+            round_offset(4),
+            &config.round_cells.chi,
+            Fr::from(0xdead),
+        )
+    });
+    assert_error_matches(result, "chi base");
+}
+
+#[test]
+fn tampered_iota_round_constant() {
+    // The round constant is only used by iota, where the state word plus the
+    // constant is split into parts.
+    let result = verify_tampered(14, vec![vec![1, 2, 3]], |_, witness| {
+        witness[round_offset(4)].round_cst += Fr::ONE
+    });
+    assert_error_matches(result, "split");
+}
+
+#[test]
+fn tampered_squeeze() {
+    // The first word of the digest is converted to bytes in the round before
+    // the last round of the final block.
+    let result = verify_tampered(14, vec![vec![1, 2, 3]], |config, witness| {
+        set_cell(
+            witness,
+            round_offset(NUM_ROUNDS - 1),
+            &config.round_cells.squeeze_from,
+            Fr::from(0xdead),
+        )
+    });
+    assert_error_matches(result, "squeeze verify packed");
+}
+
+#[test]
+fn tampered_length() {
+    // The length is only updated on the absorbing rounds and has to be kept
+    // up to the final row of the hash, where it is exposed in the table.
+    let result = verify_tampered(14, vec![vec![1, 2, 3]], |_, witness| {
+        witness[round_offset(NUM_ROUNDS)].length += 1
+    });
+    assert_error_matches(result, "length equality check");
+}
+
+#[test]
+fn tampered_data_rlc() {
+    let result = verify_tampered(14, vec![vec![1, 2, 3]], |_, witness| {
+        witness[round_offset(NUM_ROUNDS)].data_rlc = Value::known(Fr::from(0xdead))
+    });
+    assert_error_matches(result, "data_rlc equality check");
+}
+
+#[test]
+fn tampered_hash_output() {
+    // The digest in the table has to match the squeezed state bytes.
+    let result = verify_tampered(14, vec![vec![1, 2, 3]], |_, witness| {
+        witness[round_offset(NUM_ROUNDS)].hash = WordLoHi::default().into_value()
+    });
+    assert_error_matches(result, "output check");
+}
+
+#[test]
+fn packed_multi_keccak_random_inputs() {
+    let k = 14;
+    let mut rng = ChaCha20Rng::seed_from_u64(0);
+    // Random lengths over the first three blocks, so that both single and
+    // multi-block messages are covered.
+    let inputs = (0..8)
+        .map(|_| {
+            let len = rng.gen_range(0..3 * RATE);
+            (0..len).map(|_| rng.gen::<u8>()).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let digests = inputs
+        .iter()
+        .map(|input| hex::encode(keccak256(input)))
+        .collect();
+    verify::<Fr>(k, inputs, digests, true);
+}
+
+#[cfg(feature = "par-witness")]
+#[test]
+fn parallel_witness_matches_serial() {
+    use super::keccak_packed_multi::keccak;
+
+    let mut rng = ChaCha20Rng::seed_from_u64(0);
+    let inputs = (0..8)
+        .map(|_| {
+            let len = rng.gen_range(0..3 * RATE);
+            (0..len).map(|_| rng.gen::<u8>()).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let challenges = Challenges::mock(Value::known(Fr::from(7)), Value::known(Fr::from(11)));
+
+    let parallel = multi_keccak(&inputs, challenges, None).unwrap();
+    // The serial rows of each input, after the dummy first round.
+    let mut serial = Vec::new();
+    for bytes in inputs.iter() {
+        keccak(&mut serial, bytes, challenges);
+    }
+
+    let parallel = &parallel[get_num_rows_per_round()..];
+    assert_eq!(parallel.len(), serial.len());
+    for (p, s) in parallel.iter().zip(serial.iter()) {
+        assert_eq!(p.cell_values, s.cell_values);
+        assert_eq!(p.is_final, s.is_final);
+        assert_eq!(p.length, s.length);
+        p.data_rlc.zip(s.data_rlc).assert_if_known(|(p, s)| p == s);
+    }
+}
+
 fn assigned_non_zero<F: Field>(cv: &CellValue<F>) -> bool {
     match *cv {
         CellValue::Assigned(v) => !v.is_zero_vartime(),
@@ -165,6 +450,37 @@ fn variadic_size_check() {
     assert_eq!(prover1.permutation(), prover2.permutation());
 }
 
+#[test]
+fn min_num_rows_counts_padding_blocks() {
+    assert_eq!(get_num_keccak_f(0), 1);
+    assert_eq!(get_num_keccak_f(RATE - 1), 1);
+    assert_eq!(get_num_keccak_f(RATE), 2);
+    assert_eq!(get_num_keccak_f(RATE + 1), 2);
+    assert_eq!(get_num_keccak_f(2 * RATE), 3);
+
+    let rows_per_keccak_f = (NUM_ROUNDS + 1) * get_num_rows_per_round();
+    let inputs = vec![vec![], (0u8..136).collect::<Vec<_>>()];
+    assert_eq!(
+        KeccakCircuit::<Fr>::min_num_rows(&inputs),
+        get_num_rows_per_round() + 3 * rows_per_keccak_f
+    );
+}
+
+#[test]
+fn min_num_rows_picks_smallest_k() {
+    // Enough keccak_f's for the rounds, and not the lookup tables, to set k.
+    let inputs = vec![vec![0u8; 3 * RATE]];
+    let num_rows =
+        KeccakCircuit::<Fr>::min_num_rows(&inputs) + KeccakCircuit::<Fr>::unusable_rows();
+    let k = num_rows.next_power_of_two().trailing_zeros();
+
+    // Without a fixed capacity the witness only has the rows of the inputs.
+    let circuit = KeccakCircuit::<Fr>::new(0, inputs);
+    let prover = MockProver::<Fr>::run(k, &circuit, vec![]).unwrap();
+    assert_eq!(prover.verify(), Ok(()));
+    assert!(MockProver::<Fr>::run(k - 1, &circuit, vec![]).is_err());
+}
+
 #[test]
 fn test_target_part_sizes() {
     // Uniform 8 parts of 8 bits each.
@@ -142,6 +142,9 @@ impl<F: Field> ConstraintBuilder<F> {
         self.condition(q.tag_matches(Target::Storage), |cb| {
             cb.build_account_storage_constraints(q)
         });
+        self.condition(q.tag_matches(Target::TransientStorage), |cb| {
+            cb.build_transient_storage_constraints(q)
+        });
         self.condition(q.tag_matches(Target::TxAccessListAccount), |cb| {
             cb.build_tx_access_list_account_constraints(q)
         });
@@ -160,6 +163,9 @@ impl<F: Field> ConstraintBuilder<F> {
         self.condition(q.tag_matches(Target::TxLog), |cb| {
             cb.build_tx_log_constraints(q)
         });
+        self.condition(q.tag_matches(Target::TxCreatedAccount), |cb| {
+            cb.build_tx_created_account_constraints(q)
+        });
     }
 
     fn build_general_constraints(&mut self, q: &Queries<F>) {
@@ -389,6 +395,28 @@ impl<F: Field> ConstraintBuilder<F> {
         });
     }
 
+    fn build_transient_storage_constraints(&mut self, q: &Queries<F>) {
+        self.require_zero("field_tag is 0 for TransientStorage", q.field_tag());
+        // Transient storage is keyed by tx_id, so it starts out empty in every
+        // transaction.
+        self.require_word_zero("initial TransientStorage value is 0", q.initial_value());
+
+        // Transient storage is never committed to the MPT.
+        self.require_word_equal(
+            "state_root is unchanged for TransientStorage",
+            q.state_root(),
+            q.state_root_prev(),
+        );
+
+        self.condition(q.not_first_access.clone(), |cb| {
+            cb.require_word_equal(
+                "value column at Rotation::prev() equals value_prev at Rotation::cur()",
+                q.rw_table.value_prev.clone(),
+                q.value_prev_column(),
+            );
+        });
+    }
+
     fn build_tx_access_list_account_constraints(&mut self, q: &Queries<F>) {
         self.require_zero("field_tag is 0 for TxAccessListAccount", q.field_tag());
         self.require_word_zero(
@@ -442,6 +470,32 @@ impl<F: Field> ConstraintBuilder<F> {
         });
     }
 
+    fn build_tx_created_account_constraints(&mut self, q: &Queries<F>) {
+        self.require_zero("field_tag is 0 for TxCreatedAccount", q.field_tag());
+        self.require_word_zero(
+            "storage_key is 0 for TxCreatedAccount",
+            q.rw_table.storage_key.clone(),
+        );
+        self.require_word_boolean("TxCreatedAccount value is boolean", q.value());
+        // Every account starts as not created in the transaction, so only a write of
+        // a creation step can set the flag read by SELFDESTRUCT.
+        self.require_word_zero("initial TxCreatedAccount value is false", q.initial_value());
+
+        self.require_word_equal(
+            "state_root is unchanged for TxCreatedAccount",
+            q.state_root(),
+            q.state_root_prev(),
+        );
+
+        self.condition(q.not_first_access.clone(), |cb| {
+            cb.require_word_equal(
+                "value column at Rotation::prev() equals value_prev at Rotation::cur()",
+                q.rw_table.value_prev.clone(),
+                q.value_prev_column(),
+            );
+        });
+    }
+
     fn build_tx_refund_constraints(&mut self, q: &Queries<F>) {
         // 7.0. `address`, `field_tag` and `storage_key` are 0
         self.require_zero("address is 0 for TxRefund", q.rw_table.address.clone());
@@ -895,6 +895,93 @@ fn bad_initial_tx_access_list_account_value() {
     );
 }
 
+#[test]
+fn bad_initial_tx_created_account_value() {
+    let rows = vec![Rw::TxCreatedAccount {
+        rw_counter: 1,
+        is_write: false,
+        tx_id: 1,
+        account_address: address!("0x0000000000000000000000000000000004356002"),
+        is_created: true,
+        is_created_prev: true,
+    }];
+
+    let overrides = HashMap::from([
+        ((AdviceColumn::InitialValueHi, 0), Fr::ZERO),
+        ((AdviceColumn::InitialValueLo, 0), Fr::from(1)),
+        ((AdviceColumn::ValuePrevHi, 0), Fr::ZERO),
+        ((AdviceColumn::ValuePrevLo, 0), Fr::from(1)),
+    ]);
+
+    assert_error_matches(
+        verify_with_overrides(rows, overrides),
+        "initial TxCreatedAccount value is false",
+    );
+}
+
+#[test]
+fn transient_storage_ok() {
+    let account_address = address!("0x0000000000000000000000000000000004356002");
+    let storage_key = Word::from(0x30);
+    let rows = vec![
+        Rw::AccountTransientStorage {
+            rw_counter: 1,
+            is_write: true,
+            account_address,
+            storage_key,
+            value: Word::from(0x40),
+            value_prev: Word::zero(),
+            tx_id: 1,
+        },
+        Rw::AccountTransientStorage {
+            rw_counter: 2,
+            is_write: false,
+            account_address,
+            storage_key,
+            value: Word::from(0x40),
+            value_prev: Word::from(0x40),
+            tx_id: 1,
+        },
+        // A new transaction starts with empty transient storage.
+        Rw::AccountTransientStorage {
+            rw_counter: 3,
+            is_write: false,
+            account_address,
+            storage_key,
+            value: Word::zero(),
+            value_prev: Word::zero(),
+            tx_id: 2,
+        },
+    ];
+
+    assert_eq!(verify(rows), Ok(()));
+}
+
+#[test]
+fn bad_initial_transient_storage_value() {
+    let rows = vec![Rw::AccountTransientStorage {
+        rw_counter: 1,
+        is_write: true,
+        account_address: address!("0x0000000000000000000000000000000004356002"),
+        storage_key: Word::from(0x30),
+        value: Word::from(0x40),
+        value_prev: Word::zero(),
+        tx_id: 1,
+    }];
+
+    let overrides = HashMap::from([
+        ((AdviceColumn::InitialValueHi, 0), Fr::ZERO),
+        ((AdviceColumn::InitialValueLo, 0), Fr::from(1)),
+        ((AdviceColumn::ValuePrevHi, 0), Fr::ZERO),
+        ((AdviceColumn::ValuePrevLo, 0), Fr::from(1)),
+    ]);
+
+    assert_error_matches(
+        verify_with_overrides(rows, overrides),
+        "initial TransientStorage value is 0",
+    );
+}
+
 #[test]
 fn bad_initial_tx_refund_value() {
     let rows = vec![Rw::TxRefund {
@@ -4,7 +4,7 @@ use super::*;
 use gadgets::binary_number::AsBits;
 
 // The row also includes the 3 column bits from the tag
-type CopyTableRow<F> = [(Value<F>, &'static str); 12];
+type CopyTableRow<F> = [(Value<F>, &'static str); 13];
 type CopyCircuitRow<F> = [(Value<F>, &'static str); 5];
 
 /// Copy Table, used to verify copies of byte chunks between Memory, Bytecode,
@@ -21,6 +21,7 @@ pub struct CopyTable {
     pub id: WordLoHi<Column<Advice>>,
     /// The source/destination address for this copy step.  Can be memory
     /// address, byte index in the bytecode, tx call data, and tx log data.
+    /// It moves by one byte per copy step, downwards if `is_reverse` is set.
     pub addr: Column<Advice>,
     /// The end of the source buffer for the copy event.  Any data read from an
     /// address greater than or equal to this value will be 0.
@@ -36,6 +37,9 @@ pub struct CopyTable {
     pub rw_counter: Column<Advice>,
     /// Decrementing counter denoting reverse read-write counter.
     pub rwc_inc_left: Column<Advice>,
+    /// Whether the copy event copies bytes in decreasing address order, from
+    /// the last byte of the range down to the first one.
+    pub is_reverse: Column<Advice>,
     /// Selector for the tag BinaryNumberChip
     pub q_enable: Column<Fixed>,
     // We use the `BinaryNumberBits` instead of `BinaryNumberChip` in order to construct the table
@@ -60,6 +64,7 @@ impl CopyTable {
             rlc_acc: meta.advice_column_in(SecondPhase),
             rw_counter: meta.advice_column(),
             rwc_inc_left: meta.advice_column(),
+            is_reverse: meta.advice_column(),
         }
     }
 
@@ -129,12 +134,11 @@ impl CopyTable {
             };
 
             // addr
-            let copy_step_addr: u64 =
-                if is_read_step {
-                    copy_event.src_addr
-                } else {
-                    copy_event.dst_addr
-                } + (u64::try_from(step_idx).unwrap() - if is_read_step { 0 } else { 1 }) / 2u64;
+            let copy_step_addr: u64 = if is_read_step {
+                copy_event.src_addr
+            } else {
+                copy_event.dst_addr
+            } + copy_event.addr_offset(step_idx);
 
             let addr = if tag == CopyDataType::TxLog {
                 Value::known(
@@ -196,6 +200,10 @@ impl CopyTable {
                     Value::known(F::from(copy_event.rw_counter_increase_left(step_idx))),
                     "rwc_inc_left",
                 ),
+                (
+                    Value::known(F::from(copy_event.is_reverse as u64)),
+                    "is_reverse",
+                ),
                 tag_bits[0],
                 tag_bits[1],
                 tag_bits[2],
@@ -281,6 +289,7 @@ impl<F: Field> LookupTable<F> for CopyTable {
             self.rlc_acc.into(),
             self.rw_counter.into(),
             self.rwc_inc_left.into(),
+            self.is_reverse.into(),
         ];
         columns.extend(self.tag.iter().map(|c| Into::<Column<Any>>::into(*c)));
         columns
@@ -297,6 +306,7 @@ impl<F: Field> LookupTable<F> for CopyTable {
             String::from("rlc_acc"),
             String::from("rw_counter"),
             String::from("rwc_inc_left"),
+            String::from("is_reverse"),
         ];
         names.extend((0..self.tag.len()).map(|i| format!("tag_bit{i}")));
         names
@@ -318,6 +328,7 @@ impl<F: Field> LookupTable<F> for CopyTable {
             meta.query_advice(self.rlc_acc, Rotation::cur()), // rlc_acc
             meta.query_advice(self.rw_counter, Rotation::cur()), // rw_counter
             meta.query_advice(self.rwc_inc_left, Rotation::cur()), // rwc_inc_left
+            meta.query_advice(self.is_reverse, Rotation::cur()), // is_reverse
         ]
     }
 }
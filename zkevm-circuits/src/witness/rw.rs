@@ -333,6 +333,15 @@ pub enum Rw {
         field_tag: TxReceiptFieldTag,
         value: u64,
     },
+    /// TxCreatedAccount
+    TxCreatedAccount {
+        rw_counter: usize,
+        is_write: bool,
+        tx_id: usize,
+        account_address: Address,
+        is_created: bool,
+        is_created_prev: bool,
+    },
 
     /// StepState
     StepState {
@@ -545,6 +554,13 @@ impl Rw {
         }
     }
 
+    pub(crate) fn tx_created_account_value(&self) -> bool {
+        match self {
+            Self::TxCreatedAccount { is_created, .. } => *is_created,
+            _ => unreachable!(),
+        }
+    }
+
     pub(crate) fn tx_refund_value_pair(&self) -> (u64, u64) {
         match self {
             Self::TxRefund {
@@ -689,7 +705,8 @@ impl Rw {
             | Self::CallContext { rw_counter, .. }
             | Self::StepState { rw_counter, .. }
             | Self::TxLog { rw_counter, .. }
-            | Self::TxReceipt { rw_counter, .. } => *rw_counter,
+            | Self::TxReceipt { rw_counter, .. }
+            | Self::TxCreatedAccount { rw_counter, .. } => *rw_counter,
         }
     }
 
@@ -707,7 +724,8 @@ impl Rw {
             | Self::CallContext { is_write, .. }
             | Self::StepState { is_write, .. }
             | Self::TxLog { is_write, .. }
-            | Self::TxReceipt { is_write, .. } => *is_write,
+            | Self::TxReceipt { is_write, .. }
+            | Self::TxCreatedAccount { is_write, .. } => *is_write,
         }
     }
 
@@ -726,6 +744,7 @@ impl Rw {
             Self::CallContext { .. } => Target::CallContext,
             Self::TxLog { .. } => Target::TxLog,
             Self::TxReceipt { .. } => Target::TxReceipt,
+            Self::TxCreatedAccount { .. } => Target::TxCreatedAccount,
             Self::StepState { .. } => Target::StepState,
         }
     }
@@ -738,7 +757,8 @@ impl Rw {
             | Self::TxAccessListAccountStorage { tx_id, .. }
             | Self::TxRefund { tx_id, .. }
             | Self::TxLog { tx_id, .. }
-            | Self::TxReceipt { tx_id, .. } => Some(*tx_id),
+            | Self::TxReceipt { tx_id, .. }
+            | Self::TxCreatedAccount { tx_id, .. } => Some(*tx_id),
             Self::CallContext { call_id, .. }
             | Self::Stack { call_id, .. }
             | Self::Memory { call_id, .. } => Some(*call_id),
@@ -765,6 +785,9 @@ impl Rw {
             }
             | Self::AccountTransientStorage {
                 account_address, ..
+            }
+            | Self::TxCreatedAccount {
+                account_address, ..
             } => Some(*account_address),
             Self::Memory { memory_address, .. } => Some(U256::from(*memory_address).to_address()),
             Self::Stack { stack_pointer, .. } => {
@@ -803,7 +826,8 @@ impl Rw {
             | Self::TxAccessListAccount { .. }
             | Self::TxAccessListAccountStorage { .. }
             | Self::TxRefund { .. }
-            | Self::TxLog { .. } => None,
+            | Self::TxLog { .. }
+            | Self::TxCreatedAccount { .. } => None,
         }
     }
 
@@ -822,7 +846,8 @@ impl Rw {
             | Self::Account { .. }
             | Self::TxAccessListAccount { .. }
             | Self::TxLog { .. }
-            | Self::TxReceipt { .. } => None,
+            | Self::TxReceipt { .. }
+            | Self::TxCreatedAccount { .. } => None,
         }
     }
 
@@ -838,6 +863,7 @@ impl Rw {
             | Self::TxLog { value, .. } => *value,
             Self::TxAccessListAccount { is_warm, .. }
             | Self::TxAccessListAccountStorage { is_warm, .. } => U256::from(*is_warm as u64),
+            Self::TxCreatedAccount { is_created, .. } => U256::from(*is_created as u64),
             Self::Memory { byte, .. } => U256::from(u64::from(*byte)),
             Self::TxRefund { value, .. } | Self::TxReceipt { value, .. } => U256::from(*value),
         }
@@ -852,6 +878,9 @@ impl Rw {
             | Self::TxAccessListAccountStorage { is_warm_prev, .. } => {
                 Some(U256::from(*is_warm_prev as u64))
             }
+            Self::TxCreatedAccount {
+                is_created_prev, ..
+            } => Some(U256::from(*is_created_prev as u64)),
             Self::TxRefund { value_prev, .. } => Some(U256::from(*value_prev)),
             Self::Padding { .. }
             | Self::Start { .. }
@@ -970,6 +999,13 @@ impl From<Vec<Rw>> for RwMap {
                         rw_map.insert(Target::TxRefund, vec![rw]);
                     }
                 }
+                Rw::TxCreatedAccount { .. } => {
+                    if let Some(vrw) = rw_map.get_mut(&Target::TxCreatedAccount) {
+                        vrw.push(rw)
+                    } else {
+                        rw_map.insert(Target::TxCreatedAccount, vec![rw]);
+                    }
+                }
                 Rw::StepState { .. } => {
                     if let Some(vrw) = rw_map.get_mut(&Target::StepState) {
                         vrw.push(rw)
@@ -1223,6 +1259,21 @@ impl From<&operation::OperationContainer> for RwMap {
                 })
                 .collect(),
         );
+        rws.insert(
+            Target::TxCreatedAccount,
+            container
+                .tx_created_account
+                .iter()
+                .map(|op| Rw::TxCreatedAccount {
+                    rw_counter: op.rwc().into(),
+                    is_write: op.rw().is_write(),
+                    tx_id: op.op().tx_id,
+                    account_address: op.op().address,
+                    is_created: op.op().is_created,
+                    is_created_prev: op.op().is_created_prev,
+                })
+                .collect(),
+        );
         rws.insert(
             Target::StepState,
             container